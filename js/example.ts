@@ -0,0 +1,58 @@
+// Usage example of the typings, check it with `npm run typecheck` after `npm run build:package`
+import * as addon from '../pkg-npm';
+
+declare const TON_EVENT: string;
+declare const REGISTRY: string;
+declare const SHARD_STATE: string;
+
+function describeStatus(status: addon.EventStatus): string {
+    switch (status) {
+        case 'in_process':
+            return 'waiting for votes';
+        case 'confirmed':
+            return 'confirmed';
+        case 'rejected':
+            return 'rejected';
+    }
+}
+
+async function main() {
+    // loads the wasm module, the exports can't be used before it resolves
+    await addon.init();
+
+    const details = addon.getDetails(TON_EVENT);
+
+    const confirmations: string[] = details.confirmations;
+    const signatures: addon.Signature[] = details.signatures;
+    const canonical = signatures.filter((signature) => !signature.malformed).map((signature) => signature.bytes);
+    console.log(describeStatus(details.status), confirmations.length, canonical);
+
+    const json: addon.TonEventDetailsJSON = details.toJSON();
+    const lt: bigint = details.initData.eventTransactionLtBigInt;
+    console.log(json.initData.eventTransactionLt, lt);
+
+    const registry = addon.ConfigurationRegistry.fromJSON(REGISTRY);
+    const summary = registry.summarize(details);
+    if (summary?.kind === 'token_burn') {
+        const data = registry.decodeEventDataObject(details) as addon.EventDataOf<'token_burn'>;
+        const tokens: bigint = data.tokens;
+        console.log(summary.tokenBurn?.amountFormatted, tokens);
+    }
+
+    addon.getShardStateDetails(SHARD_STATE, [], (address, event, error) => {
+        if (error != null) {
+            console.error(address, error.message);
+        } else if (event != null) {
+            console.log(address, event.status);
+        }
+        return true;
+    });
+}
+
+main().catch((e) => {
+    if (addon.isTonEventDetailsError(e)) {
+        console.error(e.message);
+    } else {
+        throw e;
+    }
+});
@@ -0,0 +1,50 @@
+// Builds `nodejs` and `web` wasm-pack targets into `pkg-npm` and adds the entry point wrapper
+const { execSync } = require('child_process');
+const fs = require('fs');
+const path = require('path');
+
+const root = path.resolve(__dirname, '../..');
+// `pkg` is used by the webpack demo build
+const pkg = path.join(root, 'pkg-npm');
+
+fs.rmSync(pkg, { recursive: true, force: true });
+
+for (const [target, dir] of [['nodejs', 'node'], ['web', 'web']]) {
+    execSync(`wasm-pack build --release --target ${target} --out-dir pkg-npm/${dir}`, { cwd: root, stdio: 'inherit' });
+    // wasm-pack adds its own manifest and ignore file to every target
+    for (const file of ['package.json', '.gitignore', 'README.md', 'LICENSE_MIT', 'LICENSE_APACHE']) {
+        fs.rmSync(path.join(pkg, dir, file), { force: true });
+    }
+}
+
+for (const file of ['index.cjs', 'index.mjs', 'index.d.ts', 'index.node.d.ts', 'pool.mjs', 'worker.mjs', 'handlers.mjs']) {
+    fs.copyFileSync(path.join(__dirname, file), path.join(pkg, file));
+}
+for (const file of ['README.md', 'LICENSE_MIT', 'LICENSE_APACHE']) {
+    fs.copyFileSync(path.join(root, file), path.join(pkg, file));
+}
+
+const manifest = require(path.join(root, 'package.json'));
+const packageJson = {
+    name: manifest.name,
+    version: manifest.version,
+    author: manifest.author,
+    license: 'MIT OR Apache-2.0',
+    main: './index.cjs',
+    module: './index.mjs',
+    types: './index.d.ts',
+    exports: {
+        '.': {
+            node: {
+                types: './index.node.d.ts',
+                default: './index.cjs',
+            },
+            default: {
+                types: './index.d.ts',
+                default: './index.mjs',
+            },
+        },
+    },
+    files: ['index.cjs', 'index.mjs', 'index.d.ts', 'index.node.d.ts', 'pool.mjs', 'worker.mjs', 'handlers.mjs', 'node', 'web'],
+};
+fs.writeFileSync(path.join(pkg, 'package.json'), JSON.stringify(packageJson, null, 2) + '\n');
@@ -0,0 +1,23 @@
+// Functions available through the worker pool. Inputs and outputs must be structured cloneable,
+// so wasm objects are passed as JSON
+import * as addon from './web/ton_explorer_event_details.js';
+
+export const handlers = {
+    getDetails(accountState) {
+        const details = addon.getDetails(accountState);
+        try {
+            return details.toJSON();
+        } finally {
+            details.free();
+        }
+    },
+
+    encodePayload(details, ethAbi, proxyAddress, strict) {
+        const value = addon.TonEventDetails.fromJSON(JSON.stringify(details));
+        try {
+            return addon.encodePayload(value, ethAbi, proxyAddress, strict);
+        } finally {
+            value.free();
+        }
+    },
+};
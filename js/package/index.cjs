@@ -0,0 +1,7 @@
+// Node.js entry point, the wasm module is loaded synchronously on require
+const addon = require('./node/ton_explorer_event_details.js');
+
+module.exports = {
+    ...addon,
+    init: () => Promise.resolve(),
+};
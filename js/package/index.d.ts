@@ -0,0 +1,43 @@
+export * from './web/ton_explorer_event_details';
+
+/**
+ * Loads the wasm module. Required before calling any other function. Node.js uses
+ * `index.node.d.ts`, where `WorkerPool` is not available
+ */
+export function init(input?: RequestInfo | URL | Response | BufferSource | WebAssembly.Module): Promise<void>;
+
+export interface TaskOptions {
+    /** Cancels the task. Running tasks are interrupted by restarting their worker */
+    signal?: AbortSignal;
+}
+
+export interface WorkerPoolOptions {
+    /** Number of workers, defaults to `navigator.hardwareConcurrency`. Zero runs tasks on the current thread */
+    size?: number;
+    /** Worker script URL, defaults to `worker.mjs` next to the package entry point */
+    workerUrl?: string | URL;
+}
+
+/** Promise based API backed by a pool of Web Workers */
+export class WorkerPool {
+    constructor(options?: WorkerPoolOptions);
+
+    /**
+     * Accepts base64 or hex encoded or raw account state. With `transfer` the buffer
+     * is moved to the worker and becomes unusable in the current thread
+     */
+    getDetails(
+        accountState: Uint8Array | string,
+        options?: TaskOptions & { transfer?: boolean },
+    ): Promise<import('./web/ton_explorer_event_details').TonEventDetailsJSON>;
+
+    encodePayload(
+        details: import('./web/ton_explorer_event_details').TonEventDetailsJSON,
+        ethAbi: string,
+        proxyAddress: string,
+        options?: TaskOptions & { strict?: boolean },
+    ): Promise<string>;
+
+    /** Rejects all pending tasks and stops the workers. Later calls are rejected immediately */
+    terminate(): void;
+}
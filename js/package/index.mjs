@@ -0,0 +1,15 @@
+// Browser and bundler entry point, `init` must be awaited before using the exports
+import initWasm from './web/ton_explorer_event_details.js';
+
+export * from './web/ton_explorer_event_details.js';
+
+let initialized = null;
+
+export function init(input) {
+    if (initialized == null) {
+        initialized = initWasm(input).then(() => undefined);
+    }
+    return initialized;
+}
+
+export { WorkerPool } from './pool.mjs';
@@ -0,0 +1,4 @@
+export * from './node/ton_explorer_event_details';
+
+/** Resolves immediately, the wasm module is loaded synchronously on require */
+export function init(): Promise<void>;
@@ -0,0 +1,180 @@
+// Worker crashes in a row after which the worker is not restarted anymore
+const MAX_FAILURES = 3;
+
+// Runs functions in a pool of module workers. Falls back to the current thread when
+// workers are not available or `size` is zero
+export class WorkerPool {
+    constructor({ size, workerUrl } = {}) {
+        this.size = size ?? ((typeof navigator !== 'undefined' && navigator.hardwareConcurrency) || 2);
+        this.workerUrl = workerUrl ?? new URL('./worker.mjs', import.meta.url);
+        this.inline = this.size === 0 || typeof Worker === 'undefined';
+        this.slots = [];
+        this.queue = [];
+        this.nextId = 0;
+        this.terminated = false;
+        this.error = null;
+
+        if (!this.inline) {
+            for (let i = 0; i < this.size; ++i) {
+                this.slots.push(this._spawn());
+            }
+        }
+    }
+
+    getDetails(accountState, { signal, transfer } = {}) {
+        const transferList = transfer && accountState instanceof Uint8Array ? [accountState.buffer] : [];
+        return this._run('getDetails', [accountState], transferList, signal);
+    }
+
+    encodePayload(details, ethAbi, proxyAddress, { strict, signal } = {}) {
+        return this._run('encodePayload', [details, ethAbi, proxyAddress, strict], [], signal);
+    }
+
+    // Rejects all pending tasks and stops the workers, later calls are rejected immediately
+    terminate() {
+        this.terminated = true;
+        this._rejectQueue(abortError());
+        for (const slot of this.slots) {
+            const task = slot.task;
+            if (task != null) {
+                this._finish(slot);
+                task.reject(abortError());
+            }
+            slot.worker.terminate();
+        }
+        this.slots = [];
+    }
+
+    _run(method, args, transferList, signal) {
+        if (this.terminated) {
+            return Promise.reject(new Error('Worker pool is terminated'));
+        }
+        if (this.error != null) {
+            return Promise.reject(this.error);
+        }
+        if (signal?.aborted) {
+            return Promise.reject(abortError());
+        }
+
+        if (this.inline) {
+            // loaded lazily, so the pool itself doesn't depend on the wasm module
+            const modules = Promise.all([import('./index.mjs'), import('./handlers.mjs')]);
+            return modules.then(async ([{ init }, { handlers }]) => {
+                await init();
+                if (signal?.aborted) {
+                    throw abortError();
+                }
+                return handlers[method](...args);
+            });
+        }
+
+        return new Promise((resolve, reject) => {
+            const task = { id: this.nextId++, method, args, transferList, signal, resolve, reject };
+            task.onAbort = () => this._cancel(task);
+            signal?.addEventListener('abort', task.onAbort);
+            this.queue.push(task);
+            this._dispatch();
+        });
+    }
+
+    _spawn(slot = { task: null, failures: 0 }) {
+        slot.worker = new Worker(this.workerUrl, { type: 'module' });
+        slot.worker.onmessage = ({ data }) => {
+            slot.failures = 0;
+            const task = slot.task;
+            if (task == null || task.id !== data.id) {
+                return;
+            }
+            this._finish(slot);
+            if (data.error != null) {
+                task.reject(Object.assign(new Error(data.error.message), { name: data.error.name }));
+            } else {
+                task.resolve(data.result);
+            }
+            this._dispatch();
+        };
+        // A worker that fails to load fails again after the restart, so it is dropped
+        // after several failures in a row. Without workers left all tasks are rejected
+        slot.worker.onerror = (event) => {
+            const task = slot.task;
+            const error = new Error(event.message);
+            if (++slot.failures < MAX_FAILURES) {
+                this._restart(slot);
+            } else {
+                this._remove(slot, error);
+            }
+            task?.reject(error);
+            this._dispatch();
+        };
+        return slot;
+    }
+
+    _dispatch() {
+        for (const slot of this.slots) {
+            if (slot.task != null) {
+                continue;
+            }
+            const task = this.queue.shift();
+            if (task == null) {
+                return;
+            }
+            slot.task = task;
+            slot.worker.postMessage({ id: task.id, method: task.method, args: task.args }, task.transferList);
+        }
+    }
+
+    _remove(slot, error) {
+        if (slot.task != null) {
+            this._finish(slot);
+        }
+        slot.worker.terminate();
+        this.slots.splice(this.slots.indexOf(slot), 1);
+        if (this.slots.length === 0) {
+            this.error = new Error(`Worker pool has no running workers: ${error.message}`);
+            this._rejectQueue(this.error);
+        }
+    }
+
+    _rejectQueue(error) {
+        for (const task of this.queue.splice(0)) {
+            task.signal?.removeEventListener('abort', task.onAbort);
+            task.reject(error);
+        }
+    }
+
+    _finish(slot) {
+        const task = slot.task;
+        task.signal?.removeEventListener('abort', task.onAbort);
+        slot.task = null;
+    }
+
+    // Queued tasks are dropped, running tasks can only be interrupted by restarting the worker
+    _cancel(task) {
+        const index = this.queue.indexOf(task);
+        if (index >= 0) {
+            this.queue.splice(index, 1);
+        } else {
+            const slot = this.slots.find((slot) => slot.task === task);
+            if (slot == null) {
+                return;
+            }
+            this._restart(slot);
+        }
+        task.reject(abortError());
+        this._dispatch();
+    }
+
+    _restart(slot) {
+        if (slot.task != null) {
+            this._finish(slot);
+        }
+        slot.worker.terminate();
+        this._spawn(slot);
+    }
+}
+
+function abortError() {
+    return typeof DOMException !== 'undefined'
+        ? new DOMException('The operation was aborted', 'AbortError')
+        : Object.assign(new Error('The operation was aborted'), { name: 'AbortError' });
+}
@@ -0,0 +1,100 @@
+// Worker pool tests with a stub `Worker`, run with `npm run test:pool`
+import assert from 'node:assert/strict';
+import { afterEach, test } from 'node:test';
+
+import { WorkerPool } from './pool.mjs';
+
+// `echo` answers with the call arguments, `fail` crashes right after start, `hang` never answers
+class StubWorker {
+    static mode = 'echo';
+    static spawned = 0;
+
+    constructor() {
+        StubWorker.spawned += 1;
+        this.mode = StubWorker.mode;
+        this.terminated = false;
+        if (this.mode === 'fail') {
+            setTimeout(() => this.terminated || this.onerror({ message: 'Failed to load worker' }));
+        }
+    }
+
+    postMessage({ id, args }) {
+        if (this.mode === 'echo') {
+            setTimeout(() => this.terminated || this.onmessage({ data: { id, result: args } }));
+        }
+    }
+
+    terminate() {
+        this.terminated = true;
+    }
+}
+
+// Abort signal that counts its listeners
+function stubSignal() {
+    const listeners = new Set();
+    return {
+        aborted: false,
+        listeners,
+        addEventListener: (_, listener) => listeners.add(listener),
+        removeEventListener: (_, listener) => listeners.delete(listener),
+    };
+}
+
+globalThis.Worker = StubWorker;
+
+afterEach(() => {
+    StubWorker.mode = 'echo';
+    StubWorker.spawned = 0;
+});
+
+test('runs tasks in workers', async () => {
+    const pool = new WorkerPool({ size: 2, workerUrl: 'worker.mjs' });
+    const results = await Promise.all([pool.getDetails('a'), pool.getDetails('b'), pool.getDetails('c')]);
+    assert.deepEqual(results, [['a'], ['b'], ['c']]);
+    assert.equal(StubWorker.spawned, 2);
+    pool.terminate();
+});
+
+test('drops workers that fail to load', async () => {
+    StubWorker.mode = 'fail';
+    const pool = new WorkerPool({ size: 1, workerUrl: 'worker.mjs' });
+    const tasks = ['a', 'b', 'c', 'd'].map((state) => pool.getDetails(state));
+
+    for (const task of tasks.slice(0, 3)) {
+        await assert.rejects(task, { message: 'Failed to load worker' });
+    }
+    await assert.rejects(tasks[3], { message: 'Worker pool has no running workers: Failed to load worker' });
+    await assert.rejects(pool.getDetails('e'), { message: /no running workers/ });
+
+    await new Promise((resolve) => setTimeout(resolve, 10));
+    assert.equal(StubWorker.spawned, 3);
+    assert.equal(pool.slots.length, 0);
+});
+
+test('terminate rejects tasks and removes abort listeners', async () => {
+    StubWorker.mode = 'hang';
+    const pool = new WorkerPool({ size: 1, workerUrl: 'worker.mjs' });
+    const signal = stubSignal();
+    const running = pool.getDetails('a', { signal });
+    const queued = pool.getDetails('b', { signal });
+    assert.equal(signal.listeners.size, 2);
+
+    pool.terminate();
+    await assert.rejects(running, { name: 'AbortError' });
+    await assert.rejects(queued, { name: 'AbortError' });
+    assert.equal(signal.listeners.size, 0);
+    await assert.rejects(pool.getDetails('c'), { message: 'Worker pool is terminated' });
+});
+
+test('aborts queued tasks', async () => {
+    StubWorker.mode = 'hang';
+    const pool = new WorkerPool({ size: 1, workerUrl: 'worker.mjs' });
+    const controller = new AbortController();
+    pool.getDetails('a').catch(() => {});
+    const queued = pool.getDetails('b', { signal: controller.signal });
+
+    controller.abort();
+    await assert.rejects(queued, { name: 'AbortError' });
+    assert.equal(pool.queue.length, 0);
+    pool.terminate();
+});
@@ -0,0 +1,13 @@
+import { init } from './index.mjs';
+import { handlers } from './handlers.mjs';
+
+const ready = init();
+
+self.onmessage = async ({ data: { id, method, args } }) => {
+    try {
+        await ready;
+        self.postMessage({ id, result: handlers[method](...args) });
+    } catch (e) {
+        self.postMessage({ id, error: { name: e.name, message: e.message ?? String(e) } });
+    }
+};
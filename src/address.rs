@@ -0,0 +1,167 @@
+use std::str::FromStr;
+
+use ton_block::{MsgAddress, MsgAddressInt};
+use ton_types::SliceData;
+
+use crate::utils::Result;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AddressFormat {
+    Raw,
+    UserFriendly {
+        bounceable: bool,
+        url_safe: bool,
+        testnet: bool,
+    },
+}
+
+impl Default for AddressFormat {
+    fn default() -> Self {
+        AddressFormat::Raw
+    }
+}
+
+impl AddressFormat {
+    pub fn url_safe(self) -> bool {
+        matches!(self, AddressFormat::UserFriendly { url_safe: true, .. })
+    }
+}
+
+pub fn format_address(address: &MsgAddressInt, format: AddressFormat) -> Result<String> {
+    match format {
+        AddressFormat::Raw => Ok(address.to_string()),
+        AddressFormat::UserFriendly {
+            bounceable,
+            url_safe,
+            testnet,
+        } => encode_user_friendly(address, bounceable, url_safe, testnet),
+    }
+}
+
+/// Same as [`format_address`], but addresses without user friendly representation
+/// (anycast or non-standard ones) are kept raw
+pub fn format_any_address(address: &MsgAddressInt, format: AddressFormat) -> String {
+    match address {
+        MsgAddressInt::AddrStd(std_address) if std_address.anycast.is_none() => {
+            format_address(address, format).unwrap_or_else(|_| address.to_string())
+        }
+        _ => address.to_string(),
+    }
+}
+
+/// Formats address token values, `None` for an empty address
+pub fn format_msg_address(address: &MsgAddress, format: AddressFormat) -> Option<String> {
+    match address {
+        MsgAddress::AddrNone => None,
+        MsgAddress::AddrStd(address) => Some(format_any_address(&MsgAddressInt::AddrStd(address.clone()), format)),
+        MsgAddress::AddrVar(address) => Some(format_any_address(&MsgAddressInt::AddrVar(address.clone()), format)),
+        address => Some(address.to_string()),
+    }
+}
+
+/// Parses both raw (`wid:hex`) and user friendly (`EQ...`, `UQ...`) addresses.
+///
+/// `url_safe` selects the base64 alphabet of user friendly addresses, `None` accepts both
+pub fn parse_address(address: &str, url_safe: Option<bool>) -> Result<MsgAddressInt> {
+    if address.len() == USER_FRIENDLY_LEN && !address.contains(':') {
+        let (address, url_safe) = match url_safe {
+            Some(url_safe) => (address.to_owned(), url_safe),
+            // alphabets differ only in these two characters
+            None => (address.replace('-', "+").replace('_', "/"), false),
+        };
+        return decode_user_friendly(&address, url_safe).map(|(address, _)| address);
+    }
+    MsgAddressInt::from_str(address).map_err(|_| "Invalid address")
+}
+
+/// Decodes user friendly address encoded with the specified base64 alphabet
+pub fn decode_user_friendly(address: &str, url_safe: bool) -> Result<(MsgAddressInt, AddressFormat)> {
+    let config = if url_safe { base64::URL_SAFE } else { base64::STANDARD };
+    let data = base64::decode_config(address, config).map_err(|_| "Invalid user friendly address")?;
+    if data.len() != 36 {
+        return Err("Invalid user friendly address");
+    }
+
+    let crc = u16::from_be_bytes([data[34], data[35]]);
+    if crc16(&data[..34]) != crc {
+        return Err("Invalid user friendly address checksum");
+    }
+
+    let testnet = data[0] & TESTNET_FLAG != 0;
+    let bounceable = match data[0] & !TESTNET_FLAG {
+        BOUNCEABLE_TAG => true,
+        NON_BOUNCEABLE_TAG => false,
+        _ => return Err("Invalid user friendly address tag"),
+    };
+
+    let address = MsgAddressInt::with_standart(None, data[1] as i8, SliceData::from_raw(data[2..34].to_vec(), 256))
+        .map_err(|_| "Invalid user friendly address")?;
+
+    Ok((
+        address,
+        AddressFormat::UserFriendly {
+            bounceable,
+            url_safe,
+            testnet,
+        },
+    ))
+}
+
+pub fn encode_user_friendly(
+    address: &MsgAddressInt,
+    bounceable: bool,
+    url_safe: bool,
+    testnet: bool,
+) -> Result<String> {
+    let workchain_id = address.workchain_id();
+    if workchain_id < i8::MIN as i32 || workchain_id > i8::MAX as i32 {
+        return Err("Workchain id is out of range");
+    }
+
+    let account_id = match address {
+        MsgAddressInt::AddrStd(address) if address.anycast.is_none() => address.address.get_bytestring(0),
+        _ => return Err("Only standard addresses have user friendly representation"),
+    };
+    if account_id.len() != 32 {
+        return Err("Invalid account id length");
+    }
+
+    let mut tag = if bounceable { BOUNCEABLE_TAG } else { NON_BOUNCEABLE_TAG };
+    if testnet {
+        tag |= TESTNET_FLAG;
+    }
+
+    let mut data = Vec::with_capacity(36);
+    data.push(tag);
+    data.push(workchain_id as u8);
+    data.extend_from_slice(&account_id);
+    let crc = crc16(&data);
+    data.extend_from_slice(&crc.to_be_bytes());
+
+    Ok(if url_safe {
+        base64::encode_config(&data, base64::URL_SAFE)
+    } else {
+        base64::encode(&data)
+    })
+}
+
+// CRC-16/XMODEM
+fn crc16(data: &[u8]) -> u16 {
+    let mut crc = 0u16;
+    for byte in data {
+        crc ^= (*byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+const USER_FRIENDLY_LEN: usize = 48;
+const BOUNCEABLE_TAG: u8 = 0x11;
+const NON_BOUNCEABLE_TAG: u8 = 0x51;
+const TESTNET_FLAG: u8 = 0x80;
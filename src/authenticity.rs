@@ -0,0 +1,91 @@
+use serde::{Deserialize, Deserializer};
+use ton_types::{Cell, UInt256};
+
+use crate::contract::{self, TonEventDetails};
+use crate::utils::{self, Result};
+
+pub fn get_checked_details(
+    code: Cell,
+    data: Cell,
+    allowlist: &CodeHashAllowlist,
+    network: &str,
+    direction: EventDirection,
+) -> Result<CheckedTonEventDetails> {
+    let authenticity = allowlist.check(&code.repr_hash(), network, direction);
+    let details = contract::get_details(code, data)?;
+    Ok(CheckedTonEventDetails { details, authenticity })
+}
+
+pub struct CheckedTonEventDetails {
+    pub details: TonEventDetails,
+    pub authenticity: Authenticity,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(transparent)]
+pub struct CodeHashAllowlist {
+    entries: Vec<AllowlistEntry>,
+}
+
+impl CodeHashAllowlist {
+    pub fn from_json(json: &str) -> Result<Self> {
+        serde_json::from_str(json).map_err(|_| "Failed to parse code hash allowlist")
+    }
+
+    pub fn insert(&mut self, network: String, direction: EventDirection, code_hash: UInt256) {
+        self.entries.push(AllowlistEntry {
+            network,
+            direction,
+            code_hash,
+        });
+    }
+
+    pub fn check(&self, code_hash: &UInt256, network: &str, direction: EventDirection) -> Authenticity {
+        let mut known = false;
+        for entry in self.entries.iter().filter(|entry| &entry.code_hash == code_hash) {
+            if entry.network == network && entry.direction == direction {
+                return Authenticity::Trusted;
+            }
+            known = true;
+        }
+
+        if known {
+            Authenticity::Mismatched
+        } else {
+            Authenticity::Unknown
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct AllowlistEntry {
+    network: String,
+    direction: EventDirection,
+    #[serde(deserialize_with = "deserialize_hash")]
+    code_hash: UInt256,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventDirection {
+    TonToEth,
+    EthToTon,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Authenticity {
+    /// Code hash is allowed for the specified network and direction
+    Trusted,
+    /// Code hash is not in the allowlist
+    Unknown,
+    /// Code hash is allowed, but for another network or direction
+    Mismatched,
+}
+
+fn deserialize_hash<'de, D>(deserializer: D) -> Result<UInt256, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let hash = String::deserialize(deserializer)?;
+    utils::parse_hash(&hash).map_err(serde::de::Error::custom)
+}
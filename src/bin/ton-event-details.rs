@@ -0,0 +1,86 @@
+use std::io::Read;
+
+use ton_explorer_event_details::{disasm, inspect};
+
+const USAGE: &str = "Usage:
+    ton-event-details inspect [--depth <N>] [BOC]
+    ton-event-details disasm [--abi <PATH>]... [BOC]
+
+BOC can be a path to a file, a base64 or hex encoded string. Reads stdin if omitted";
+
+fn main() {
+    if let Err(e) = run() {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+}
+
+fn run() -> Result<(), String> {
+    let mut args = std::env::args().skip(1);
+    match args.next().as_deref() {
+        Some("inspect") => run_inspect(args),
+        Some("disasm") => run_disasm(args),
+        _ => Err(USAGE.to_owned()),
+    }
+}
+
+fn run_inspect(mut args: impl Iterator<Item = String>) -> Result<(), String> {
+    let mut max_depth = None;
+    let mut input = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--depth" => {
+                let depth = args.next().ok_or(USAGE)?;
+                max_depth = Some(depth.parse::<usize>().map_err(|_| "Invalid depth")?);
+            }
+            _ if input.is_none() => input = Some(arg),
+            _ => return Err(USAGE.to_owned()),
+        }
+    }
+
+    let boc = read_boc(input)?;
+    let tree = inspect::inspect_boc(&boc, max_depth)?;
+    println!("{}", serde_json::to_string_pretty(&tree).map_err(|e| e.to_string())?);
+    Ok(())
+}
+
+fn run_disasm(mut args: impl Iterator<Item = String>) -> Result<(), String> {
+    let mut abis = Vec::new();
+    let mut input = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--abi" => {
+                let path = args.next().ok_or(USAGE)?;
+                let abi = std::fs::File::open(&path).map_err(|e| format!("{}: {}", path, e))?;
+                abis.push(ton_abi::Contract::load(abi).map_err(|_| "Failed to parse contract abi")?);
+            }
+            _ if input.is_none() => input = Some(arg),
+            _ => return Err(USAGE.to_owned()),
+        }
+    }
+
+    let boc = read_boc(input)?;
+    let code =
+        ton_types::deserialize_tree_of_cells(&mut std::io::Cursor::new(boc)).map_err(|_| "Failed to parse Cell")?;
+    print!("{}", disasm::disassemble(&code, &disasm::known_functions(&abis))?);
+    Ok(())
+}
+
+fn read_boc(input: Option<String>) -> Result<Vec<u8>, String> {
+    let input = match input {
+        Some(input) if std::path::Path::new(&input).is_file() => {
+            return std::fs::read(&input).map_err(|e| e.to_string());
+        }
+        Some(input) => input,
+        None => {
+            let mut input = String::new();
+            std::io::stdin().read_to_string(&mut input).map_err(|e| e.to_string())?;
+            input
+        }
+    };
+
+    let input = input.trim();
+    hex::decode(input)
+        .or_else(|_| base64::decode(input))
+        .map_err(|_| "Failed to decode BOC".to_owned())
+}
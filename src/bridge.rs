@@ -0,0 +1,83 @@
+use ton_abi::{Contract, Token, TokenValue};
+use ton_block::MsgAddressInt;
+use ton_types::Cell;
+
+use crate::contract::{self, TryParse, INVALID_ABI};
+use crate::utils::Result;
+
+/// Runs `getBridgeConfiguration` and `getAccounts` getters from the deployed Bridge contract ABI
+pub fn get_details(code: Cell, data: Cell, bridge_abi: &Contract) -> Result<BridgeDetails> {
+    let get_bridge_configuration = bridge_abi
+        .function("getBridgeConfiguration")
+        .map_err(|_| "Bridge abi doesn't have getBridgeConfiguration function")?;
+    let get_accounts = bridge_abi
+        .function("getAccounts")
+        .map_err(|_| "Bridge abi doesn't have getAccounts function")?;
+
+    let configuration = contract::run_local(code.clone(), data.clone(), get_bridge_configuration)?;
+    let accounts = contract::run_local(code, data, get_accounts)?;
+    parse_details(configuration, accounts)
+}
+
+/// Expects a configuration tuple and two arrays of TON addresses and Ethereum addresses
+pub fn parse_details(configuration: Vec<Token>, accounts: Vec<Token>) -> Result<BridgeDetails> {
+    let configuration = configuration.into_iter().next().try_parse()?;
+
+    let mut accounts = accounts.into_iter();
+    let ton_accounts: Vec<MsgAddressInt> = accounts.next().try_parse()?;
+    let eth_accounts: Vec<ethabi::Address> = accounts.next().try_parse()?;
+
+    if ton_accounts.len() != eth_accounts.len() {
+        return Err(INVALID_ABI);
+    }
+
+    let relays = ton_accounts
+        .into_iter()
+        .zip(eth_accounts.into_iter())
+        .map(|(ton_address, eth_address)| BridgeRelay {
+            ton_address,
+            eth_address,
+        })
+        .collect();
+
+    Ok(BridgeDetails { configuration, relays })
+}
+
+pub struct BridgeDetails {
+    pub configuration: BridgeConfiguration,
+    pub relays: Vec<BridgeRelay>,
+}
+
+pub struct BridgeRelay {
+    pub ton_address: MsgAddressInt,
+    pub eth_address: ethabi::Address,
+}
+
+pub struct BridgeConfiguration {
+    pub event_configuration_required_confirmations: u16,
+    pub event_configuration_required_rejections: u16,
+    pub bridge_configuration_update_required_confirmations: u16,
+    pub bridge_configuration_update_required_rejections: u16,
+    pub bridge_relay_update_required_confirmations: u16,
+    pub bridge_relay_update_required_rejections: u16,
+    pub active: bool,
+}
+
+impl TryParse<BridgeConfiguration> for TokenValue {
+    fn try_parse(self) -> Result<BridgeConfiguration> {
+        let mut tuple = match self {
+            TokenValue::Tuple(tuple) => tuple.into_iter(),
+            _ => return Err(INVALID_ABI),
+        };
+
+        Ok(BridgeConfiguration {
+            event_configuration_required_confirmations: tuple.next().try_parse()?,
+            event_configuration_required_rejections: tuple.next().try_parse()?,
+            bridge_configuration_update_required_confirmations: tuple.next().try_parse()?,
+            bridge_configuration_update_required_rejections: tuple.next().try_parse()?,
+            bridge_relay_update_required_confirmations: tuple.next().try_parse()?,
+            bridge_relay_update_required_rejections: tuple.next().try_parse()?,
+            active: tuple.next().try_parse()?,
+        })
+    }
+}
@@ -0,0 +1,149 @@
+use num_bigint::BigUint;
+use ton_abi::{Contract, Function, Token, TokenValue};
+use ton_block::MsgAddressInt;
+use ton_types::Cell;
+
+use crate::contract::{self, TryParse, INVALID_ABI};
+use crate::utils::Result;
+
+/// Runs `getDetails` getter from the deployed TonEventConfiguration contract ABI
+pub fn get_ton_event_configuration_details(
+    code: Cell,
+    data: Cell,
+    configuration_abi: &Contract,
+) -> Result<TonEventConfigurationDetails> {
+    let tokens = contract::run_local(code, data, abi_get_details(configuration_abi)?)?;
+    parse_ton_event_configuration_details(tokens)
+}
+
+/// Runs `getDetails` getter from the deployed EthereumEventConfiguration contract ABI
+pub fn get_ethereum_event_configuration_details(
+    code: Cell,
+    data: Cell,
+    configuration_abi: &Contract,
+) -> Result<EthereumEventConfigurationDetails> {
+    let tokens = contract::run_local(code, data, abi_get_details(configuration_abi)?)?;
+    parse_ethereum_event_configuration_details(tokens)
+}
+
+/// Expects the basic init data tuple and TON event configuration init data tuple
+pub fn parse_ton_event_configuration_details(tokens: Vec<Token>) -> Result<TonEventConfigurationDetails> {
+    tokens.try_parse()
+}
+
+/// Expects the basic init data tuple and Ethereum event configuration init data tuple
+pub fn parse_ethereum_event_configuration_details(tokens: Vec<Token>) -> Result<EthereumEventConfigurationDetails> {
+    tokens.try_parse()
+}
+
+fn abi_get_details(configuration_abi: &Contract) -> Result<&Function> {
+    configuration_abi
+        .function("getDetails")
+        .map_err(|_| "Configuration abi doesn't have getDetails function")
+}
+
+pub struct TonEventConfigurationDetails {
+    pub basic_init_data: BasicConfigurationInitData,
+    pub init_data: TonEventConfigurationInitData,
+}
+
+impl TryParse<TonEventConfigurationDetails> for Vec<Token> {
+    fn try_parse(self) -> Result<TonEventConfigurationDetails> {
+        let mut tuple = self.into_iter();
+
+        Ok(TonEventConfigurationDetails {
+            basic_init_data: tuple.next().try_parse()?,
+            init_data: tuple.next().try_parse()?,
+        })
+    }
+}
+
+pub struct EthereumEventConfigurationDetails {
+    pub basic_init_data: BasicConfigurationInitData,
+    pub init_data: EthereumEventConfigurationInitData,
+}
+
+impl TryParse<EthereumEventConfigurationDetails> for Vec<Token> {
+    fn try_parse(self) -> Result<EthereumEventConfigurationDetails> {
+        let mut tuple = self.into_iter();
+
+        Ok(EthereumEventConfigurationDetails {
+            basic_init_data: tuple.next().try_parse()?,
+            init_data: tuple.next().try_parse()?,
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct BasicConfigurationInitData {
+    pub event_abi: String,
+    pub event_required_confirmations: u16,
+    pub event_required_rejections: u16,
+    pub event_code: Cell,
+    pub bridge_address: MsgAddressInt,
+    pub event_initial_balance: BigUint,
+    pub meta: Cell,
+}
+
+impl TryParse<BasicConfigurationInitData> for TokenValue {
+    fn try_parse(self) -> Result<BasicConfigurationInitData> {
+        let mut tuple = match self {
+            TokenValue::Tuple(tuple) => tuple.into_iter(),
+            _ => return Err(INVALID_ABI),
+        };
+
+        Ok(BasicConfigurationInitData {
+            event_abi: tuple.next().try_parse()?,
+            event_required_confirmations: tuple.next().try_parse()?,
+            event_required_rejections: tuple.next().try_parse()?,
+            event_code: tuple.next().try_parse()?,
+            bridge_address: tuple.next().try_parse()?,
+            event_initial_balance: tuple.next().try_parse()?,
+            meta: tuple.next().try_parse()?,
+        })
+    }
+}
+
+pub struct TonEventConfigurationInitData {
+    pub event_address: MsgAddressInt,
+    pub proxy_address: ethabi::Address,
+    pub start_timestamp: u32,
+}
+
+impl TryParse<TonEventConfigurationInitData> for TokenValue {
+    fn try_parse(self) -> Result<TonEventConfigurationInitData> {
+        let mut tuple = match self {
+            TokenValue::Tuple(tuple) => tuple.into_iter(),
+            _ => return Err(INVALID_ABI),
+        };
+
+        Ok(TonEventConfigurationInitData {
+            event_address: tuple.next().try_parse()?,
+            proxy_address: tuple.next().try_parse()?,
+            start_timestamp: tuple.next().try_parse()?,
+        })
+    }
+}
+
+pub struct EthereumEventConfigurationInitData {
+    pub event_address: ethabi::Address,
+    pub event_blocks_to_confirm: u16,
+    pub proxy_address: MsgAddressInt,
+    pub start_block_number: u32,
+}
+
+impl TryParse<EthereumEventConfigurationInitData> for TokenValue {
+    fn try_parse(self) -> Result<EthereumEventConfigurationInitData> {
+        let mut tuple = match self {
+            TokenValue::Tuple(tuple) => tuple.into_iter(),
+            _ => return Err(INVALID_ABI),
+        };
+
+        Ok(EthereumEventConfigurationInitData {
+            event_address: tuple.next().try_parse()?,
+            event_blocks_to_confirm: tuple.next().try_parse()?,
+            proxy_address: tuple.next().try_parse()?,
+            start_block_number: tuple.next().try_parse()?,
+        })
+    }
+}
@@ -1,29 +1,94 @@
 use std::collections::HashMap;
+use std::convert::TryFrom;
 
 use ethabi::Address;
 use num_bigint::BigUint;
 use num_traits::ToPrimitive;
 use serde::Deserialize;
 use ton_abi::{Function, Param, ParamType, Token, TokenValue};
-use ton_block::{CommonMsgInfo, ExternalInboundMessageHeader, Message, MsgAddress, MsgAddressInt};
-use ton_types::{Cell, UInt256};
+use ton_block::{
+    Account, CommonMsgInfo, ExternalInboundMessageHeader, Message, MsgAddress, MsgAddressInt, Serializable, StateInit,
+};
+use ton_types::{BuilderData, Cell, HashmapE, HashmapType, SliceData, UInt256};
 
 use crate::tvm;
-use crate::utils::Result;
+use crate::utils::{self, Result};
 
 pub fn get_details(code: Cell, data: Cell) -> Result<TonEventDetails> {
-    let tokens = execute_message(code, data)?;
+    let tokens = run_local(code, data, &abi_get_details())?;
     let details = tokens.try_parse()?;
     Ok(details)
 }
 
-fn execute_message(code: Cell, data: Cell) -> Result<Vec<Token>> {
+pub fn compute_event_address(
+    event_code: Cell,
+    init_data: &TonEventInitData,
+    workchain_id: i32,
+) -> Result<MsgAddressInt> {
+    let workchain_id = i8::try_from(workchain_id).map_err(|_| "Workchain id is out of range")?;
+
+    let state_init = StateInit {
+        code: Some(event_code),
+        data: Some(build_event_data(init_data)?),
+        ..Default::default()
+    };
+
+    let hash = state_init
+        .serialize()
+        .map_err(|_| "Failed to serialize state init")?
+        .repr_hash();
+
+    MsgAddressInt::with_standart(None, workchain_id, SliceData::from_raw(hash.as_slice().to_vec(), 256))
+        .map_err(|_| "Failed to build event address")
+}
+
+pub fn verify_event_address(account: &Account, event_code: Cell) -> Result<bool> {
+    let address = account.get_addr().ok_or("Account doesn't have address")?;
+    let (code, data) = utils::decode_account(account)?;
+    if code.repr_hash() != event_code.repr_hash() {
+        return Ok(false);
+    }
+
+    let details = get_details(code, data)?;
+    let expected = compute_event_address(event_code, &details.init_data, address.workchain_id())?;
+    Ok(&expected == address)
+}
+
+fn build_event_data(init_data: &TonEventInitData) -> Result<Cell> {
+    let mut map = HashmapE::with_bit_len(DATA_MAP_KEYLEN);
+
+    // event contracts are deployed without public key
+    let mut pubkey = BuilderData::new();
+    pubkey
+        .append_raw(&[0; 32], 256)
+        .map_err(|_| "Failed to build event data")?;
+    map.set_builder(data_map_key(0)?, &pubkey)
+        .map_err(|_| "Failed to build event data")?;
+
+    let init_data = TokenValue::pack_values_into_chain(&init_data.to_tokens(), Vec::new(), 2)
+        .map_err(|_| "Failed to pack event init data")?;
+    map.set_builder(data_map_key(1)?, &init_data)
+        .map_err(|_| "Failed to build event data")?;
+
+    map.write_to_new_cell()
+        .and_then(|data| data.into_cell())
+        .map_err(|_| "Failed to build event data")
+}
+
+fn data_map_key(key: u64) -> Result<SliceData> {
+    key.write_to_new_cell()
+        .and_then(|key| key.into_cell())
+        .map(SliceData::from)
+        .map_err(|_| "Failed to build event data")
+}
+
+const DATA_MAP_KEYLEN: usize = 64;
+
+pub(crate) fn run_local(code: Cell, data: Cell, abi: &Function) -> Result<Vec<Token>> {
     let mut header = HashMap::new();
     header.insert("time".to_owned(), TokenValue::Time(1));
     header.insert("expire".to_owned(), TokenValue::Expire(1000));
 
-    let abi = abi_get_details();
-
     let message = abi
         .encode_input(&header, &[], false, None)
         .map_err(|_| "Failed to encode input")?;
@@ -93,6 +158,42 @@ pub struct TonEventInitData {
     pub configuration_meta: Cell,
 }
 
+impl TonEventInitData {
+    fn to_tokens(&self) -> Vec<Token> {
+        let address = match self.ton_event_configuration.clone() {
+            MsgAddressInt::AddrStd(address) => MsgAddress::AddrStd(address),
+            MsgAddressInt::AddrVar(address) => MsgAddress::AddrVar(address),
+        };
+
+        vec![
+            uint_token(
+                "eventTransaction",
+                BigUint::from_bytes_be(self.event_transaction.as_slice()),
+                256,
+            ),
+            uint_token("eventTransactionLt", self.event_transaction_lt.into(), 64),
+            uint_token("eventTimestamp", self.event_timestamp.into(), 32),
+            uint_token("eventIndex", self.event_index.into(), 32),
+            token("eventData", TokenValue::Cell(self.event_data.clone())),
+            token("tonEventConfiguration", TokenValue::Address(address)),
+            uint_token("requiredConfirmations", self.required_confirmations.into(), 16),
+            uint_token("requiredRejects", self.required_rejections.into(), 16),
+            token("configurationMeta", TokenValue::Cell(self.configuration_meta.clone())),
+        ]
+    }
+}
+
+fn uint_token(name: &str, number: BigUint, size: usize) -> Token {
+    token(name, TokenValue::Uint(ton_abi::Uint { number, size }))
+}
+
+fn token(name: &str, value: TokenValue) -> Token {
+    Token {
+        name: name.to_owned(),
+        value,
+    }
+}
+
 impl TryParse<TonEventInitData> for TokenValue {
     fn try_parse(self) -> Result<TonEventInitData> {
         let mut tuple = match self {
@@ -114,6 +215,7 @@ impl TryParse<TonEventInitData> for TokenValue {
     }
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum EventStatus {
     InProcess,
     Confirmed,
@@ -155,10 +257,12 @@ impl TryParse<Vec<MsgAddressInt>> for TokenValue {
 impl TryParse<MsgAddressInt> for TokenValue {
     fn try_parse(self) -> Result<MsgAddressInt> {
         match self {
+            // anycast info and variable length addresses are kept as is
             TokenValue::Address(address) => match address {
                 MsgAddress::AddrStd(address) => Ok(MsgAddressInt::AddrStd(address)),
                 MsgAddress::AddrVar(address) => Ok(MsgAddressInt::AddrVar(address)),
-                _ => Err(INVALID_ABI),
+                MsgAddress::AddrNone => Err("Address is empty"),
+                MsgAddress::AddrExt(_) => Err("External addresses are not supported"),
             },
             _ => Err(INVALID_ABI),
         }
@@ -201,6 +305,24 @@ impl TryParse<u16> for TokenValue {
     }
 }
 
+impl TryParse<Vec<Address>> for TokenValue {
+    fn try_parse(self) -> Result<Vec<Address>> {
+        match self {
+            TokenValue::Array(tokens) => tokens.into_iter().map(TryParse::<Address>::try_parse).collect(),
+            _ => Err(INVALID_ABI),
+        }
+    }
+}
+
+impl TryParse<bool> for TokenValue {
+    fn try_parse(self) -> Result<bool> {
+        match self {
+            TokenValue::Bool(value) => Ok(value),
+            _ => Err(INVALID_ABI),
+        }
+    }
+}
+
 impl TryParse<Vec<Vec<u8>>> for TokenValue {
     fn try_parse(self) -> Result<Vec<Vec<u8>>> {
         match self {
@@ -219,6 +341,15 @@ impl TryParse<Vec<u8>> for TokenValue {
     }
 }
 
+impl TryParse<String> for TokenValue {
+    fn try_parse(self) -> Result<String> {
+        match self {
+            TokenValue::Bytes(bytes) => String::from_utf8(bytes).map_err(|_| INVALID_ABI),
+            _ => Err(INVALID_ABI),
+        }
+    }
+}
+
 impl TryParse<u8> for TokenValue {
     fn try_parse(self) -> Result<u8> {
         match self {
@@ -300,14 +431,18 @@ where
     }
 }
 
-trait TryParse<T>: Sized {
+pub(crate) trait TryParse<T>: Sized {
     fn try_parse(self) -> Result<T>;
 }
 
-const INVALID_ABI: &str = "Invalid ABI";
+pub(crate) const INVALID_ABI: &str = "Invalid ABI";
 
 pub fn abi_get_details() -> Function {
-    let abi = serde_json::from_str::<GetDetailsAbiFunction>(ABI).unwrap();
+    parse_getter_abi(ABI)
+}
+
+pub(crate) fn parse_getter_abi(abi: &str) -> Function {
+    let abi = serde_json::from_str::<GetterAbiFunction>(abi).unwrap();
     let mut abi = Function {
         abi_version: 2,
         name: abi.name,
@@ -333,7 +468,7 @@ pub fn abi_get_details() -> Function {
 }
 
 #[derive(Debug, Clone, PartialEq, Deserialize)]
-struct GetDetailsAbiFunction {
+struct GetterAbiFunction {
     pub name: String,
     pub outputs: Vec<Param>,
 }
@@ -0,0 +1,307 @@
+use serde_json::Value;
+use ton_abi::token::Detokenizer;
+use ton_abi::{Contract, Token, TokenValue};
+use ton_block::{CommonMsgInfo, Message, MsgAddressInt, Serializable, Transaction};
+use ton_types::{SliceData, UInt256};
+
+use crate::address::{self, AddressFormat};
+use crate::utils::Result;
+
+pub struct DecodedTransaction {
+    pub hash: UInt256,
+    pub lt: u64,
+    pub now: u32,
+    pub in_msg: Option<DecodedMessage>,
+    pub out_msgs: Vec<DecodedMessage>,
+    pub events: Vec<DecodedMessage>,
+}
+
+impl DecodedTransaction {
+    pub fn to_json(&self, format: AddressFormat) -> Result<Value> {
+        self.to_value(format)
+    }
+
+    pub fn to_value<T: DecodedValue>(&self, format: AddressFormat) -> Result<T> {
+        let messages_to_value = |messages: &[DecodedMessage]| {
+            messages
+                .iter()
+                .map(|message| message.to_value(format))
+                .collect::<Result<Vec<_>>>()
+                .and_then(T::array)
+        };
+
+        T::object(vec![
+            ("hash", T::string(&self.hash.to_hex_string())),
+            ("lt", T::integer(&self.lt)?),
+            ("now", T::number(self.now)),
+            (
+                "in_msg",
+                optional(self.in_msg.as_ref().map(|message| message.to_value(format)))?,
+            ),
+            ("out_msgs", messages_to_value(&self.out_msgs)?),
+            ("events", messages_to_value(&self.events)?),
+        ])
+    }
+}
+
+pub struct DecodedMessage {
+    pub hash: UInt256,
+    pub kind: MessageKind,
+    pub src: Option<MsgAddressInt>,
+    pub dst: Option<MsgAddressInt>,
+    pub body: Option<DecodedBody>,
+}
+
+impl DecodedMessage {
+    pub fn to_json(&self, format: AddressFormat) -> Result<Value> {
+        self.to_value(format)
+    }
+
+    pub fn to_value<T: DecodedValue>(&self, format: AddressFormat) -> Result<T> {
+        let format_address = |address: &Option<MsgAddressInt>| match address {
+            Some(address) => T::string(&address::format_any_address(address, format)),
+            None => T::null(),
+        };
+
+        T::object(vec![
+            ("hash", T::string(&self.hash.to_hex_string())),
+            ("type", T::string(self.kind.as_str())),
+            ("src", format_address(&self.src)),
+            ("dst", format_address(&self.dst)),
+            ("body", optional(self.body.as_ref().map(|body| body.to_value(format)))?),
+        ])
+    }
+}
+
+pub struct DecodedBody {
+    /// `call`, `output` or `event`
+    pub kind: &'static str,
+    pub name: String,
+    pub tokens: Vec<Token>,
+}
+
+impl DecodedBody {
+    pub fn to_value<T: DecodedValue>(&self, format: AddressFormat) -> Result<T> {
+        T::object(vec![
+            ("kind", T::string(self.kind)),
+            ("name", T::string(&self.name)),
+            ("tokens", tokens_to_value(&self.tokens, format)?),
+        ])
+    }
+}
+
+/// Representation of decoded values, JSON and JS objects with `BigInt` integers
+/// are built by the same code
+pub trait DecodedValue: Sized {
+    fn null() -> Self;
+    fn string(value: &str) -> Self;
+    fn number(value: u32) -> Self;
+    /// Logical time and `int`/`uint` token values
+    fn integer(value: &dyn ToString) -> Result<Self>;
+    fn array(values: Vec<Self>) -> Result<Self>;
+    fn object(fields: Vec<(&str, Self)>) -> Result<Self>;
+    /// Other token values as returned by the ABI detokenizer
+    fn json(value: Value) -> Result<Self>;
+}
+
+/// Integers are kept as decimal strings
+impl DecodedValue for Value {
+    fn null() -> Self {
+        Value::Null
+    }
+
+    fn string(value: &str) -> Self {
+        Value::from(value)
+    }
+
+    fn number(value: u32) -> Self {
+        Value::from(value)
+    }
+
+    fn integer(value: &dyn ToString) -> Result<Self> {
+        Ok(Value::String(value.to_string()))
+    }
+
+    fn array(values: Vec<Self>) -> Result<Self> {
+        Ok(Value::Array(values))
+    }
+
+    fn object(fields: Vec<(&str, Self)>) -> Result<Self> {
+        Ok(Value::Object(
+            fields
+                .into_iter()
+                .map(|(name, value)| (name.to_owned(), value))
+                .collect(),
+        ))
+    }
+
+    fn json(value: Value) -> Result<Self> {
+        Ok(value)
+    }
+}
+
+fn optional<T: DecodedValue>(value: Option<Result<T>>) -> Result<T> {
+    value.unwrap_or_else(|| Ok(T::null()))
+}
+
+pub fn decode_transaction(transaction: &Transaction, abis: &[Contract]) -> Result<DecodedTransaction> {
+    let hash = transaction
+        .serialize()
+        .map_err(|_| "Failed to serialize transaction")?
+        .repr_hash();
+
+    let in_msg = transaction
+        .read_in_msg()
+        .map_err(|_| "Failed to read inbound message")?
+        .map(|message| decode_message(&message, abis))
+        .transpose()?;
+
+    let mut out_msgs = Vec::new();
+    let mut events = Vec::new();
+    let mut error = None;
+    transaction
+        .iterate_out_msgs(|message| {
+            let target = match message.header() {
+                CommonMsgInfo::ExtOutMsgInfo(_) => &mut events,
+                _ => &mut out_msgs,
+            };
+            match decode_message(&message, abis) {
+                Ok(message) => target.push(message),
+                Err(e) => {
+                    error = Some(e);
+                    return Ok(false);
+                }
+            }
+            Ok(true)
+        })
+        .map_err(|_| "Failed to iterate transaction out messages")?;
+
+    if let Some(e) = error {
+        return Err(e);
+    }
+
+    Ok(DecodedTransaction {
+        hash,
+        lt: transaction.logical_time(),
+        now: transaction.now(),
+        in_msg,
+        out_msgs,
+        events,
+    })
+}
+
+pub fn decode_message(message: &Message, abis: &[Contract]) -> Result<DecodedMessage> {
+    let hash = message
+        .serialize()
+        .map_err(|_| "Failed to serialize message")?
+        .repr_hash();
+
+    let kind = match message.header() {
+        CommonMsgInfo::IntMsgInfo(_) => MessageKind::Internal,
+        CommonMsgInfo::ExtInMsgInfo(_) => MessageKind::ExternalIn,
+        CommonMsgInfo::ExtOutMsgInfo(_) => MessageKind::ExternalOut,
+    };
+
+    Ok(DecodedMessage {
+        hash,
+        kind,
+        src: message.src(),
+        dst: message.dst(),
+        body: message.body().and_then(|body| decode_body(body, kind, abis)),
+    })
+}
+
+fn decode_body(body: SliceData, kind: MessageKind, abis: &[Contract]) -> Option<DecodedBody> {
+    abis.iter().find_map(|abi| {
+        let decoded = match kind {
+            MessageKind::Internal => decode_function_call(abi, body.clone(), true)
+                .or_else(|| decode_function_output(abi, body.clone(), true)),
+            MessageKind::ExternalIn => decode_function_call(abi, body.clone(), false),
+            MessageKind::ExternalOut => {
+                decode_event(abi, body.clone()).or_else(|| decode_function_output(abi, body.clone(), false))
+            }
+        };
+        decoded.map(|(kind, name, tokens)| DecodedBody { kind, name, tokens })
+    })
+}
+
+type Decoded = (&'static str, String, Vec<Token>);
+
+fn decode_function_call(abi: &Contract, body: SliceData, internal: bool) -> Option<Decoded> {
+    abi.decode_input(body, internal)
+        .ok()
+        .map(|decoded| ("call", decoded.function_name, decoded.tokens))
+}
+
+fn decode_function_output(abi: &Contract, body: SliceData, internal: bool) -> Option<Decoded> {
+    let id = body.clone().get_next_u32().ok()?;
+    let function = abi.function_by_id(id, false).ok()?;
+    function
+        .decode_output(body, internal)
+        .ok()
+        .map(|tokens| ("output", function.name.clone(), tokens))
+}
+
+fn decode_event(abi: &Contract, body: SliceData) -> Option<Decoded> {
+    let id = body.clone().get_next_u32().ok()?;
+    let event = abi.event_by_id(id).ok()?;
+    event
+        .decode_input(body)
+        .ok()
+        .map(|tokens| ("event", event.name.clone(), tokens))
+}
+
+#[derive(Copy, Clone)]
+pub enum MessageKind {
+    Internal,
+    ExternalIn,
+    ExternalOut,
+}
+
+impl MessageKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            MessageKind::Internal => "internal",
+            MessageKind::ExternalIn => "external_in",
+            MessageKind::ExternalOut => "external_out",
+        }
+    }
+}
+
+/// Converts tokens into JSON object, formatting addresses with the specified format
+pub fn tokens_to_json(tokens: &[Token], format: AddressFormat) -> Result<Value> {
+    tokens_to_value(tokens, format)
+}
+
+pub fn tokens_to_value<T: DecodedValue>(tokens: &[Token], format: AddressFormat) -> Result<T> {
+    let fields = tokens
+        .iter()
+        .map(|token| Ok((token.name.as_str(), token_value_to_value(&token.value, format)?)))
+        .collect::<Result<Vec<_>>>()?;
+    T::object(fields)
+}
+
+fn token_value_to_value<T: DecodedValue>(value: &TokenValue, format: AddressFormat) -> Result<T> {
+    match value {
+        TokenValue::Address(address) => Ok(address::format_msg_address(address, format)
+            .map(|address| T::string(&address))
+            .unwrap_or_else(T::null)),
+        TokenValue::Uint(value) => T::integer(&value.number),
+        TokenValue::Int(value) => T::integer(&value.number),
+        TokenValue::Tuple(tokens) => tokens_to_value(tokens, format),
+        TokenValue::Array(values) | TokenValue::FixedArray(values) => values
+            .iter()
+            .map(|value| token_value_to_value(value, format))
+            .collect::<Result<Vec<_>>>()
+            .and_then(T::array),
+        value => {
+            let token = Token {
+                name: String::new(),
+                value: value.clone(),
+            };
+            let json = Detokenizer::detokenize(&[token]).map_err(|_| "Failed to convert tokens")?;
+            let mut json = serde_json::from_str::<Value>(&json).map_err(|_| "Failed to convert tokens")?;
+            T::json(json.get_mut("").map(Value::take).unwrap_or_default())
+        }
+    }
+}
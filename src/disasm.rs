@@ -0,0 +1,987 @@
+use std::collections::HashMap;
+
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
+use ton_abi::Contract;
+use ton_types::{BuilderData, Cell, HashmapE, HashmapType, SliceData};
+
+use crate::contract;
+use crate::utils::Result;
+
+pub fn disassemble(code: &Cell, functions: &HashMap<u32, String>) -> Result<String> {
+    let mut disassembler = Disassembler::new(functions);
+    disassembler.code(SliceData::from(code), 0);
+    Ok(disassembler.output)
+}
+
+/// Collects keys of the function selector dictionaries found in the code
+pub fn find_function_ids(code: &Cell) -> Result<Vec<u32>> {
+    let functions = HashMap::new();
+    let mut disassembler = Disassembler::new(&functions);
+    disassembler.code(SliceData::from(code), 0);
+
+    let mut function_ids = disassembler.function_ids;
+    function_ids.sort_unstable();
+    function_ids.dedup();
+    Ok(function_ids)
+}
+
+/// Functions from the ABIs and the built-in event contract `getDetails`, used for labels
+pub fn known_functions(abis: &[Contract]) -> HashMap<u32, String> {
+    let mut functions = HashMap::new();
+
+    let get_details = contract::abi_get_details();
+    functions.insert(get_details.get_input_id(), get_details.name);
+
+    functions.extend(abi_functions(abis));
+    functions
+}
+
+pub fn abi_functions(abis: &[Contract]) -> HashMap<u32, String> {
+    let mut functions = HashMap::new();
+    for abi in abis {
+        for function in abi.functions().values() {
+            functions.insert(function.get_input_id(), function.name.clone());
+        }
+    }
+
+    functions
+}
+
+struct Disassembler<'a> {
+    functions: &'a HashMap<u32, String>,
+    function_ids: Vec<u32>,
+    output: String,
+}
+
+impl<'a> Disassembler<'a> {
+    fn new(functions: &'a HashMap<u32, String>) -> Self {
+        Self {
+            functions,
+            function_ids: Vec::new(),
+            output: String::new(),
+        }
+    }
+
+    fn code(&mut self, mut slice: SliceData, indent: usize) {
+        loop {
+            while slice.remaining_bits() > 0 {
+                let checkpoint = self.output.len();
+                let mut next = slice.clone();
+                match self.instruction(&mut next, indent) {
+                    Ok(()) => slice = next,
+                    Err(_) => {
+                        self.output.truncate(checkpoint);
+                        self.line(indent, &format!(".blob x{{{}}}", slice.to_hex_string()));
+                        return;
+                    }
+                }
+            }
+
+            // code continues in the last reference
+            match slice.remaining_references() {
+                0 => return,
+                1 => match slice.checked_drain_reference() {
+                    Ok(cell) => slice = SliceData::from(cell),
+                    Err(_) => return,
+                },
+                count => {
+                    self.line(indent, &format!(";; {} unused references", count));
+                    return;
+                }
+            }
+        }
+    }
+
+    fn instruction(&mut self, slice: &mut SliceData, indent: usize) -> Result<()> {
+        let (name, args) = OPCODES
+            .iter()
+            .find(|(prefix, bits, _, _)| peek(slice, *bits as usize) == Some(*prefix))
+            .map(|(_, bits, name, args)| slice.get_next_int(*bits as usize).map(|_| (*name, args)))
+            .ok_or(INVALID_CODE)?
+            .map_err(|_| INVALID_CODE)?;
+
+        match args {
+            Args::None => self.line(indent, name),
+            Args::Uint(bits) => {
+                let value = read_uint(slice, *bits)?;
+                self.line(indent, &format!("{} {}", name, value));
+            }
+            Args::UintPlus1(bits) => {
+                let value = read_uint(slice, *bits)? + 1;
+                self.line(indent, &format!("{} {}", name, value));
+            }
+            Args::Int(bits) => {
+                let value = read_int(slice, *bits)?;
+                self.push_int(indent, name, value.into());
+            }
+            Args::Stack(bits) => {
+                let i = read_uint(slice, *bits)?;
+                self.line(indent, &format!("{} s{}", name, i));
+            }
+            Args::Stack1 => {
+                let i = read_uint(slice, 4)?;
+                self.line(indent, &format!("{} s1,s{}", name, i));
+            }
+            Args::Stack2 => {
+                let (i, j) = (read_uint(slice, 4)?, read_uint(slice, 4)?);
+                self.line(indent, &format!("{} s{},s{}", name, i, j));
+            }
+            Args::Stack3 => {
+                let (i, j, k) = (read_uint(slice, 4)?, read_uint(slice, 4)?, read_uint(slice, 4)?);
+                self.line(indent, &format!("{} s{},s{},s{}", name, i, j, k));
+            }
+            Args::Pair(a, b) => {
+                let (i, j) = (read_uint(slice, 4)?, read_uint(slice, 4)?);
+                self.line(indent, &format!("{} {},{}", name, i + a, j + b));
+            }
+            Args::Control => {
+                let i = read_uint(slice, 4)?;
+                self.line(indent, &format!("{} c{}", name, i));
+            }
+            Args::Cell => {
+                let cell = read_reference(slice)?;
+                self.line(
+                    indent,
+                    &format!("{} x{{{}}}", name, SliceData::from(cell).to_hex_string()),
+                );
+            }
+            Args::Code => {
+                let cell = read_reference(slice)?;
+                self.block(indent, name, SliceData::from(cell));
+            }
+            Args::Code2 => {
+                let first = read_reference(slice)?;
+                let second = read_reference(slice)?;
+                self.block(indent, name, SliceData::from(first));
+                self.block(indent, "", SliceData::from(second));
+            }
+            Args::Special(special) => self.special(slice, indent, name, *special)?,
+        }
+
+        Ok(())
+    }
+
+    fn special(&mut self, slice: &mut SliceData, indent: usize, name: &str, special: Special) -> Result<()> {
+        match special {
+            Special::PushIntTiny => {
+                let value = read_uint(slice, 4)? as i64;
+                let value = if value <= 10 { value } else { value - 16 };
+                self.push_int(indent, name, value.into());
+            }
+            Special::PushIntLong => {
+                let len = read_uint(slice, 5)? as usize;
+                let value = read_big_int(slice, 8 * len + 19)?;
+                self.push_int(indent, name, value);
+            }
+            Special::PushSliceShort => {
+                let len = read_uint(slice, 4)? as usize;
+                let data = read_slice(slice, 8 * len + 4, 0)?;
+                self.line(indent, &format!("{} {}", name, data));
+            }
+            Special::PushSliceRefs => {
+                let refs = read_uint(slice, 2)? as usize + 1;
+                let len = read_uint(slice, 5)? as usize;
+                let data = read_slice(slice, 8 * len + 1, refs)?;
+                self.line(indent, &format!("{} {}", name, data));
+            }
+            Special::PushSliceLong => {
+                let refs = read_uint(slice, 3)? as usize;
+                let len = read_uint(slice, 7)? as usize;
+                let data = read_slice(slice, 8 * len + 6, refs)?;
+                self.line(indent, &format!("{} {}", name, data));
+            }
+            Special::PushContShort => {
+                let len = read_uint(slice, 4)? as usize;
+                let code = read_continuation(slice, 8 * len, 0)?;
+                self.block(indent, name, code);
+            }
+            Special::PushContLong => {
+                let refs = read_uint(slice, 2)? as usize;
+                let len = read_uint(slice, 7)? as usize;
+                let code = read_continuation(slice, 8 * len, refs)?;
+                self.block(indent, name, code);
+            }
+            Special::StSliceConst => {
+                let refs = read_uint(slice, 2)? as usize;
+                let len = read_uint(slice, 3)? as usize;
+                let data = read_slice(slice, 8 * len + 2, refs)?;
+                self.line(indent, &format!("{} {}", name, data));
+            }
+            Special::SdBegins => {
+                let len = read_uint(slice, 7)? as usize;
+                let data = read_slice(slice, 8 * len + 3, 0)?;
+                self.line(indent, &format!("{} {}", name, data));
+            }
+            Special::DictPushConst => {
+                let key_len = read_uint(slice, 10)? as usize;
+                let root = read_reference(slice)?;
+                let entries = read_dictionary(root, key_len)?;
+                if key_len == FUNCTION_ID_LEN {
+                    self.function_ids.extend(entries.iter().map(|(key, _)| *key as u32));
+                }
+
+                self.line(indent, &format!("{} {} {{", name, key_len));
+                for (key, value) in entries {
+                    let header = match self.dictionary_label(key, key_len) {
+                        Some(label) => format!("{} => {{ ;; {}", key, label),
+                        None => format!("{} => {{", key),
+                    };
+                    self.line(indent + 1, &header);
+                    self.code(value, indent + 2);
+                    self.line(indent + 1, "}");
+                }
+                self.line(indent, "}");
+            }
+        }
+        Ok(())
+    }
+
+    fn dictionary_label(&self, key: i64, key_len: usize) -> Option<String> {
+        match key {
+            key if key_len == FUNCTION_ID_LEN => self.functions.get(&(key as u32)).cloned(),
+            0 => Some("recv_internal".to_owned()),
+            -1 => Some("recv_external".to_owned()),
+            -2 => Some("run_ticktock".to_owned()),
+            _ => None,
+        }
+    }
+
+    fn push_int(&mut self, indent: usize, name: &str, value: BigInt) {
+        let label = value.to_u32().and_then(|id| self.functions.get(&id));
+        match label {
+            Some(label) => self.line(indent, &format!("{} {} ;; {}", name, value, label)),
+            None => self.line(indent, &format!("{} {}", name, value)),
+        }
+    }
+
+    fn block(&mut self, indent: usize, name: &str, code: SliceData) {
+        match name {
+            "" => self.line(indent, "{"),
+            name => self.line(indent, &format!("{} {{", name)),
+        }
+        self.code(code, indent + 1);
+        self.line(indent, "}");
+    }
+
+    fn line(&mut self, indent: usize, text: &str) {
+        for _ in 0..indent {
+            self.output.push_str("  ");
+        }
+        self.output.push_str(text);
+        self.output.push('\n');
+    }
+}
+
+pub(crate) fn read_dictionary(root: Cell, key_len: usize) -> Result<Vec<(i64, SliceData)>> {
+    if key_len == 0 || key_len > 64 {
+        return Err(INVALID_CODE);
+    }
+
+    let mut entries = Vec::new();
+    HashmapE::with_hashmap(key_len, Some(root))
+        .iterate_slices_with_keys(|mut key, value| {
+            let key = key.get_next_int(key_len)?;
+            // keys are signed integers
+            let key = if key_len < 64 && (key >> (key_len - 1)) & 1 == 1 {
+                key as i64 - (1i64 << key_len)
+            } else {
+                key as i64
+            };
+            entries.push((key, value));
+            Ok(true)
+        })
+        .map_err(|_| INVALID_CODE)?;
+
+    Ok(entries)
+}
+
+fn peek(slice: &SliceData, bits: usize) -> Option<u32> {
+    slice.clone().get_next_int(bits).ok().map(|value| value as u32)
+}
+
+fn read_uint(slice: &mut SliceData, bits: usize) -> Result<u64> {
+    slice.get_next_int(bits).map_err(|_| INVALID_CODE)
+}
+
+fn read_int(slice: &mut SliceData, bits: usize) -> Result<i64> {
+    let value = read_uint(slice, bits)? as i64;
+    Ok(if (value >> (bits - 1)) & 1 == 1 {
+        value - (1 << bits)
+    } else {
+        value
+    })
+}
+
+fn read_big_int(slice: &mut SliceData, bits: usize) -> Result<BigInt> {
+    let data = slice.get_next_bits(bits).map_err(|_| INVALID_CODE)?;
+    // data is left aligned, so shift out the padding
+    Ok(BigInt::from_signed_bytes_be(&data) >> (data.len() * 8 - bits))
+}
+
+fn read_reference(slice: &mut SliceData) -> Result<Cell> {
+    slice.checked_drain_reference().map_err(|_| INVALID_CODE)
+}
+
+fn read_continuation(slice: &mut SliceData, bits: usize, refs: usize) -> Result<SliceData> {
+    let data = slice.get_next_bits(bits).map_err(|_| INVALID_CODE)?;
+
+    let mut builder = BuilderData::new();
+    builder.append_raw(&data, bits).map_err(|_| INVALID_CODE)?;
+    for _ in 0..refs {
+        builder
+            .checked_append_reference(read_reference(slice)?)
+            .map_err(|_| INVALID_CODE)?;
+    }
+
+    builder.into_cell().map(SliceData::from).map_err(|_| INVALID_CODE)
+}
+
+// reads data with completion tag and formats it in Fift notation
+fn read_slice(slice: &mut SliceData, bits: usize, refs: usize) -> Result<String> {
+    let data = slice.get_next_bits(bits).map_err(|_| INVALID_CODE)?;
+    let bit = |i: usize| (data[i / 8] >> (7 - i % 8)) & 1 == 1;
+
+    let len = (0..bits).rev().find(|i| bit(*i)).unwrap_or(0);
+
+    let mut result = String::from("x{");
+    for nibble in 0..len / 4 {
+        let value = (0..4).fold(0, |acc, i| (acc << 1) | bit(nibble * 4 + i) as u8);
+        result.push_str(&format!("{:X}", value));
+    }
+    if len % 4 != 0 {
+        let value = (0..4).fold(0, |acc, i| {
+            let offset = len / 4 * 4 + i;
+            (acc << 1) | (offset < len && bit(offset) || offset == len) as u8
+        });
+        result.push_str(&format!("{:X}_", value));
+    }
+    result.push('}');
+
+    for _ in 0..refs {
+        let cell = read_reference(slice)?;
+        result.push_str(&format!(" x{{{}}}", SliceData::from(cell).to_hex_string()));
+    }
+
+    Ok(result)
+}
+
+#[derive(Copy, Clone)]
+enum Args {
+    None,
+    Uint(usize),
+    UintPlus1(usize),
+    Int(usize),
+    Stack(usize),
+    Stack1,
+    Stack2,
+    Stack3,
+    Pair(u64, u64),
+    Control,
+    Cell,
+    Code,
+    Code2,
+    Special(Special),
+}
+
+#[derive(Copy, Clone)]
+enum Special {
+    PushIntTiny,
+    PushIntLong,
+    PushSliceShort,
+    PushSliceRefs,
+    PushSliceLong,
+    PushContShort,
+    PushContLong,
+    StSliceConst,
+    SdBegins,
+    DictPushConst,
+}
+
+const INVALID_CODE: &str = "Invalid code";
+const FUNCTION_ID_LEN: usize = 32;
+
+// (prefix, prefix bits, name, arguments). More specific opcodes must precede shorter prefixes
+#[rustfmt::skip]
+static OPCODES: &[(u32, u8, &str, Args)] = &[
+    // stack manipulation
+    (0x00, 8, "NOP", Args::None),
+    (0x01, 8, "SWAP", Args::None),
+    (0x0, 4, "XCHG", Args::Stack(4)),
+    (0x10, 8, "XCHG", Args::Stack2),
+    (0x11, 8, "XCHG", Args::Stack(8)),
+    (0x1, 4, "XCHG", Args::Stack1),
+    (0x20, 8, "DUP", Args::None),
+    (0x21, 8, "OVER", Args::None),
+    (0x2, 4, "PUSH", Args::Stack(4)),
+    (0x30, 8, "DROP", Args::None),
+    (0x31, 8, "NIP", Args::None),
+    (0x3, 4, "POP", Args::Stack(4)),
+    (0x4, 4, "XCHG3", Args::Stack3),
+    (0x50, 8, "XCHG2", Args::Stack2),
+    (0x51, 8, "XCPU", Args::Stack2),
+    (0x52, 8, "PUXC", Args::Stack2),
+    (0x53, 8, "PUSH2", Args::Stack2),
+    (0x540, 12, "XCHG3", Args::Stack3),
+    (0x541, 12, "XC2PU", Args::Stack3),
+    (0x542, 12, "XCPUXC", Args::Stack3),
+    (0x543, 12, "XCPU2", Args::Stack3),
+    (0x544, 12, "PUXC2", Args::Stack3),
+    (0x545, 12, "PUXCPU", Args::Stack3),
+    (0x546, 12, "PU2XC", Args::Stack3),
+    (0x547, 12, "PUSH3", Args::Stack3),
+    (0x55, 8, "BLKSWAP", Args::Pair(1, 1)),
+    (0x56, 8, "PUSH", Args::Stack(8)),
+    (0x57, 8, "POP", Args::Stack(8)),
+    (0x58, 8, "ROT", Args::None),
+    (0x59, 8, "ROTREV", Args::None),
+    (0x5A, 8, "SWAP2", Args::None),
+    (0x5B, 8, "DROP2", Args::None),
+    (0x5C, 8, "DUP2", Args::None),
+    (0x5D, 8, "OVER2", Args::None),
+    (0x5E, 8, "REVERSE", Args::Pair(2, 0)),
+    (0x5F0, 12, "BLKDROP", Args::Uint(4)),
+    (0x5F, 8, "BLKPUSH", Args::Pair(0, 0)),
+    (0x60, 8, "PICK", Args::None),
+    (0x61, 8, "ROLL", Args::None),
+    (0x62, 8, "ROLLREV", Args::None),
+    (0x63, 8, "BLKSWX", Args::None),
+    (0x64, 8, "REVX", Args::None),
+    (0x65, 8, "DROPX", Args::None),
+    (0x66, 8, "TUCK", Args::None),
+    (0x67, 8, "XCHGX", Args::None),
+    (0x68, 8, "DEPTH", Args::None),
+    (0x69, 8, "CHKDEPTH", Args::None),
+    (0x6A, 8, "ONLYTOPX", Args::None),
+    (0x6B, 8, "ONLYX", Args::None),
+    (0x6C, 8, "BLKDROP2", Args::Pair(0, 0)),
+    // tuples
+    (0x6D, 8, "NULL", Args::None),
+    (0x6E, 8, "ISNULL", Args::None),
+    (0x6F0, 12, "TUPLE", Args::Uint(4)),
+    (0x6F1, 12, "INDEX", Args::Uint(4)),
+    (0x6F2, 12, "UNTUPLE", Args::Uint(4)),
+    (0x6F3, 12, "UNPACKFIRST", Args::Uint(4)),
+    (0x6F4, 12, "EXPLODE", Args::Uint(4)),
+    (0x6F5, 12, "SETINDEX", Args::Uint(4)),
+    (0x6F6, 12, "INDEXQ", Args::Uint(4)),
+    (0x6F7, 12, "SETINDEXQ", Args::Uint(4)),
+    (0x6F80, 16, "TUPLEVAR", Args::None),
+    (0x6F81, 16, "INDEXVAR", Args::None),
+    (0x6F82, 16, "UNTUPLEVAR", Args::None),
+    (0x6F83, 16, "UNPACKFIRSTVAR", Args::None),
+    (0x6F84, 16, "EXPLODEVAR", Args::None),
+    (0x6F85, 16, "SETINDEXVAR", Args::None),
+    (0x6F86, 16, "INDEXVARQ", Args::None),
+    (0x6F87, 16, "SETINDEXVARQ", Args::None),
+    (0x6F88, 16, "TLEN", Args::None),
+    (0x6F89, 16, "QTLEN", Args::None),
+    (0x6F8A, 16, "ISTUPLE", Args::None),
+    (0x6F8B, 16, "LAST", Args::None),
+    (0x6F8C, 16, "TPUSH", Args::None),
+    (0x6F8D, 16, "TPOP", Args::None),
+    (0x6FA0, 16, "NULLSWAPIF", Args::None),
+    (0x6FA1, 16, "NULLSWAPIFNOT", Args::None),
+    (0x6FA2, 16, "NULLROTRIF", Args::None),
+    (0x6FA3, 16, "NULLROTRIFNOT", Args::None),
+    (0x6FA4, 16, "NULLSWAPIF2", Args::None),
+    (0x6FA5, 16, "NULLSWAPIFNOT2", Args::None),
+    (0x6FA6, 16, "NULLROTRIF2", Args::None),
+    (0x6FA7, 16, "NULLROTRIFNOT2", Args::None),
+    // constants
+    (0x7, 4, "PUSHINT", Args::Special(Special::PushIntTiny)),
+    (0x80, 8, "PUSHINT", Args::Int(8)),
+    (0x81, 8, "PUSHINT", Args::Int(16)),
+    (0x82, 8, "PUSHINT", Args::Special(Special::PushIntLong)),
+    (0x83FF, 16, "PUSHNAN", Args::None),
+    (0x83, 8, "PUSHPOW2", Args::UintPlus1(8)),
+    (0x84, 8, "PUSHPOW2DEC", Args::UintPlus1(8)),
+    (0x85, 8, "PUSHNEGPOW2", Args::UintPlus1(8)),
+    (0x88, 8, "PUSHREF", Args::Cell),
+    (0x89, 8, "PUSHREFSLICE", Args::Cell),
+    (0x8A, 8, "PUSHREFCONT", Args::Code),
+    (0x8B, 8, "PUSHSLICE", Args::Special(Special::PushSliceShort)),
+    (0x8C, 8, "PUSHSLICE", Args::Special(Special::PushSliceRefs)),
+    (0x8D, 8, "PUSHSLICE", Args::Special(Special::PushSliceLong)),
+    (0x47, 7, "PUSHCONT", Args::Special(Special::PushContLong)),
+    (0x9, 4, "PUSHCONT", Args::Special(Special::PushContShort)),
+    // arithmetic
+    (0xA0, 8, "ADD", Args::None),
+    (0xA1, 8, "SUB", Args::None),
+    (0xA2, 8, "SUBR", Args::None),
+    (0xA3, 8, "NEGATE", Args::None),
+    (0xA4, 8, "INC", Args::None),
+    (0xA5, 8, "DEC", Args::None),
+    (0xA6, 8, "ADDCONST", Args::Int(8)),
+    (0xA7, 8, "MULCONST", Args::Int(8)),
+    (0xA8, 8, "MUL", Args::None),
+    (0xA904, 16, "DIV", Args::None),
+    (0xA905, 16, "DIVR", Args::None),
+    (0xA906, 16, "DIVC", Args::None),
+    (0xA908, 16, "MOD", Args::None),
+    (0xA90C, 16, "DIVMOD", Args::None),
+    (0xA90D, 16, "DIVMODR", Args::None),
+    (0xA90E, 16, "DIVMODC", Args::None),
+    (0xA925, 16, "RSHIFTR", Args::None),
+    (0xA926, 16, "RSHIFTC", Args::None),
+    (0xA984, 16, "MULDIV", Args::None),
+    (0xA985, 16, "MULDIVR", Args::None),
+    (0xA98C, 16, "MULDIVMOD", Args::None),
+    (0xA9A4, 16, "MULRSHIFT", Args::None),
+    (0xA9A5, 16, "MULRSHIFTR", Args::None),
+    (0xA9C4, 16, "LSHIFTDIV", Args::None),
+    (0xA9C5, 16, "LSHIFTDIVR", Args::None),
+    (0xAA, 8, "LSHIFT", Args::UintPlus1(8)),
+    (0xAB, 8, "RSHIFT", Args::UintPlus1(8)),
+    (0xAC, 8, "LSHIFT", Args::None),
+    (0xAD, 8, "RSHIFT", Args::None),
+    (0xAE, 8, "POW2", Args::None),
+    (0xB0, 8, "AND", Args::None),
+    (0xB1, 8, "OR", Args::None),
+    (0xB2, 8, "XOR", Args::None),
+    (0xB3, 8, "NOT", Args::None),
+    (0xB4, 8, "FITS", Args::UintPlus1(8)),
+    (0xB5, 8, "UFITS", Args::UintPlus1(8)),
+    (0xB600, 16, "FITSX", Args::None),
+    (0xB601, 16, "UFITSX", Args::None),
+    (0xB602, 16, "BITSIZE", Args::None),
+    (0xB603, 16, "UBITSIZE", Args::None),
+    (0xB608, 16, "MIN", Args::None),
+    (0xB609, 16, "MAX", Args::None),
+    (0xB60A, 16, "MINMAX", Args::None),
+    (0xB60B, 16, "ABS", Args::None),
+    (0xB7A0, 16, "QADD", Args::None),
+    (0xB7A1, 16, "QSUB", Args::None),
+    (0xB7A8, 16, "QMUL", Args::None),
+    // comparison
+    (0xB8, 8, "SGN", Args::None),
+    (0xB9, 8, "LESS", Args::None),
+    (0xBA, 8, "EQUAL", Args::None),
+    (0xBB, 8, "LEQ", Args::None),
+    (0xBC, 8, "GREATER", Args::None),
+    (0xBD, 8, "NEQ", Args::None),
+    (0xBE, 8, "GEQ", Args::None),
+    (0xBF, 8, "CMP", Args::None),
+    (0xC0, 8, "EQINT", Args::Int(8)),
+    (0xC1, 8, "LESSINT", Args::Int(8)),
+    (0xC2, 8, "GTINT", Args::Int(8)),
+    (0xC3, 8, "NEQINT", Args::Int(8)),
+    (0xC4, 8, "ISNAN", Args::None),
+    (0xC5, 8, "CHKNAN", Args::None),
+    (0xC700, 16, "SEMPTY", Args::None),
+    (0xC701, 16, "SDEMPTY", Args::None),
+    (0xC702, 16, "SREMPTY", Args::None),
+    (0xC703, 16, "SDFIRST", Args::None),
+    (0xC704, 16, "SDLEXCMP", Args::None),
+    (0xC705, 16, "SDEQ", Args::None),
+    (0xC708, 16, "SDPFX", Args::None),
+    (0xC709, 16, "SDPFXREV", Args::None),
+    (0xC70A, 16, "SDPPFX", Args::None),
+    (0xC70B, 16, "SDPPFXREV", Args::None),
+    (0xC70C, 16, "SDSFX", Args::None),
+    (0xC70D, 16, "SDSFXREV", Args::None),
+    (0xC70E, 16, "SDPSFX", Args::None),
+    (0xC70F, 16, "SDPSFXREV", Args::None),
+    (0xC710, 16, "SDCNTLEAD0", Args::None),
+    (0xC711, 16, "SDCNTLEAD1", Args::None),
+    (0xC712, 16, "SDCNTTRAIL0", Args::None),
+    (0xC713, 16, "SDCNTTRAIL1", Args::None),
+    // cell serialization
+    (0xC8, 8, "NEWC", Args::None),
+    (0xC9, 8, "ENDC", Args::None),
+    (0xCA, 8, "STI", Args::UintPlus1(8)),
+    (0xCB, 8, "STU", Args::UintPlus1(8)),
+    (0xCC, 8, "STREF", Args::None),
+    (0xCD, 8, "ENDCST", Args::None),
+    (0xCE, 8, "STSLICE", Args::None),
+    (0xCF00, 16, "STIX", Args::None),
+    (0xCF01, 16, "STUX", Args::None),
+    (0xCF02, 16, "STIXR", Args::None),
+    (0xCF03, 16, "STUXR", Args::None),
+    (0xCF04, 16, "STIXQ", Args::None),
+    (0xCF05, 16, "STUXQ", Args::None),
+    (0xCF06, 16, "STIXRQ", Args::None),
+    (0xCF07, 16, "STUXRQ", Args::None),
+    (0xCF08, 16, "STI", Args::UintPlus1(8)),
+    (0xCF09, 16, "STU", Args::UintPlus1(8)),
+    (0xCF0A, 16, "STIR", Args::UintPlus1(8)),
+    (0xCF0B, 16, "STUR", Args::UintPlus1(8)),
+    (0xCF0C, 16, "STIQ", Args::UintPlus1(8)),
+    (0xCF0D, 16, "STUQ", Args::UintPlus1(8)),
+    (0xCF0E, 16, "STIRQ", Args::UintPlus1(8)),
+    (0xCF0F, 16, "STURQ", Args::UintPlus1(8)),
+    (0xCF10, 16, "STREF", Args::None),
+    (0xCF11, 16, "STBREF", Args::None),
+    (0xCF12, 16, "STSLICE", Args::None),
+    (0xCF13, 16, "STB", Args::None),
+    (0xCF14, 16, "STREFR", Args::None),
+    (0xCF15, 16, "STBREFR", Args::None),
+    (0xCF16, 16, "STSLICER", Args::None),
+    (0xCF17, 16, "STBR", Args::None),
+    (0xCF18, 16, "STREFQ", Args::None),
+    (0xCF19, 16, "STBREFQ", Args::None),
+    (0xCF1A, 16, "STSLICEQ", Args::None),
+    (0xCF1B, 16, "STBQ", Args::None),
+    (0xCF1C, 16, "STREFRQ", Args::None),
+    (0xCF1D, 16, "STBREFRQ", Args::None),
+    (0xCF1E, 16, "STSLICERQ", Args::None),
+    (0xCF1F, 16, "STBRQ", Args::None),
+    (0xCF20, 16, "STREFCONST", Args::Cell),
+    (0xCF23, 16, "ENDXC", Args::None),
+    (0xCF28, 16, "STILE4", Args::None),
+    (0xCF29, 16, "STULE4", Args::None),
+    (0xCF2A, 16, "STILE8", Args::None),
+    (0xCF2B, 16, "STULE8", Args::None),
+    (0xCF30, 16, "BDEPTH", Args::None),
+    (0xCF31, 16, "BBITS", Args::None),
+    (0xCF32, 16, "BREFS", Args::None),
+    (0xCF33, 16, "BBITREFS", Args::None),
+    (0xCF35, 16, "BREMBITS", Args::None),
+    (0xCF36, 16, "BREMREFS", Args::None),
+    (0xCF37, 16, "BREMBITREFS", Args::None),
+    (0xCF38, 16, "BCHKBITS", Args::UintPlus1(8)),
+    (0xCF39, 16, "BCHKBITS", Args::None),
+    (0xCF3A, 16, "BCHKREFS", Args::None),
+    (0xCF3B, 16, "BCHKBITREFS", Args::None),
+    (0xCF40, 16, "STZEROES", Args::None),
+    (0xCF41, 16, "STONES", Args::None),
+    (0xCF42, 16, "STSAME", Args::None),
+    (0xCF81, 16, "STZERO", Args::None),
+    (0xCF83, 16, "STONE", Args::None),
+    (0x19F, 9, "STSLICECONST", Args::Special(Special::StSliceConst)),
+    // cell deserialization
+    (0xD0, 8, "CTOS", Args::None),
+    (0xD1, 8, "ENDS", Args::None),
+    (0xD2, 8, "LDI", Args::UintPlus1(8)),
+    (0xD3, 8, "LDU", Args::UintPlus1(8)),
+    (0xD4, 8, "LDREF", Args::None),
+    (0xD5, 8, "LDREFRTOS", Args::None),
+    (0xD6, 8, "LDSLICE", Args::UintPlus1(8)),
+    (0xD700, 16, "LDIX", Args::None),
+    (0xD701, 16, "LDUX", Args::None),
+    (0xD702, 16, "PLDIX", Args::None),
+    (0xD703, 16, "PLDUX", Args::None),
+    (0xD704, 16, "LDIXQ", Args::None),
+    (0xD705, 16, "LDUXQ", Args::None),
+    (0xD706, 16, "PLDIXQ", Args::None),
+    (0xD707, 16, "PLDUXQ", Args::None),
+    (0xD708, 16, "LDI", Args::UintPlus1(8)),
+    (0xD709, 16, "LDU", Args::UintPlus1(8)),
+    (0xD70A, 16, "PLDI", Args::UintPlus1(8)),
+    (0xD70B, 16, "PLDU", Args::UintPlus1(8)),
+    (0xD70C, 16, "LDIQ", Args::UintPlus1(8)),
+    (0xD70D, 16, "LDUQ", Args::UintPlus1(8)),
+    (0xD70E, 16, "PLDIQ", Args::UintPlus1(8)),
+    (0xD70F, 16, "PLDUQ", Args::UintPlus1(8)),
+    (0xD718, 16, "LDSLICEX", Args::None),
+    (0xD719, 16, "PLDSLICEX", Args::None),
+    (0xD71A, 16, "LDSLICEXQ", Args::None),
+    (0xD71B, 16, "PLDSLICEXQ", Args::None),
+    (0xD71C, 16, "LDSLICE", Args::UintPlus1(8)),
+    (0xD71D, 16, "PLDSLICE", Args::UintPlus1(8)),
+    (0xD71E, 16, "LDSLICEQ", Args::UintPlus1(8)),
+    (0xD71F, 16, "PLDSLICEQ", Args::UintPlus1(8)),
+    (0xD720, 16, "SDCUTFIRST", Args::None),
+    (0xD721, 16, "SDSKIPFIRST", Args::None),
+    (0xD722, 16, "SDCUTLAST", Args::None),
+    (0xD723, 16, "SDSKIPLAST", Args::None),
+    (0xD724, 16, "SDSUBSTR", Args::None),
+    (0xD726, 16, "SDBEGINSX", Args::None),
+    (0xD727, 16, "SDBEGINSXQ", Args::None),
+    (0x35CA, 14, "SDBEGINS", Args::Special(Special::SdBegins)),
+    (0x35CB, 14, "SDBEGINSQ", Args::Special(Special::SdBegins)),
+    (0xD730, 16, "SCUTFIRST", Args::None),
+    (0xD731, 16, "SSKIPFIRST", Args::None),
+    (0xD732, 16, "SCUTLAST", Args::None),
+    (0xD733, 16, "SSKIPLAST", Args::None),
+    (0xD734, 16, "SUBSLICE", Args::None),
+    (0xD736, 16, "SPLIT", Args::None),
+    (0xD737, 16, "SPLITQ", Args::None),
+    (0xD739, 16, "XCTOS", Args::None),
+    (0xD73A, 16, "XLOAD", Args::None),
+    (0xD73B, 16, "XLOADQ", Args::None),
+    (0xD741, 16, "SCHKBITS", Args::None),
+    (0xD742, 16, "SCHKREFS", Args::None),
+    (0xD743, 16, "SCHKBITREFS", Args::None),
+    (0xD745, 16, "SCHKBITSQ", Args::None),
+    (0xD746, 16, "SCHKREFSQ", Args::None),
+    (0xD747, 16, "SCHKBITREFSQ", Args::None),
+    (0xD748, 16, "PLDREFVAR", Args::None),
+    (0xD749, 16, "SBITS", Args::None),
+    (0xD74A, 16, "SREFS", Args::None),
+    (0xD74B, 16, "SBITREFS", Args::None),
+    (0x35D3, 14, "PLDREFIDX", Args::Uint(2)),
+    (0xD750, 16, "LDILE4", Args::None),
+    (0xD751, 16, "LDULE4", Args::None),
+    (0xD752, 16, "LDILE8", Args::None),
+    (0xD753, 16, "LDULE8", Args::None),
+    (0xD754, 16, "PLDILE4", Args::None),
+    (0xD755, 16, "PLDULE4", Args::None),
+    (0xD756, 16, "PLDILE8", Args::None),
+    (0xD757, 16, "PLDULE8", Args::None),
+    (0xD760, 16, "LDZEROES", Args::None),
+    (0xD761, 16, "LDONES", Args::None),
+    (0xD762, 16, "LDSAME", Args::None),
+    (0xD764, 16, "SDEPTH", Args::None),
+    (0xD765, 16, "CDEPTH", Args::None),
+    // control flow
+    (0xD8, 8, "EXECUTE", Args::None),
+    (0xD9, 8, "JMPX", Args::None),
+    (0xDA, 8, "CALLXARGS", Args::Pair(0, 0)),
+    (0xDB0, 12, "CALLXARGS", Args::Uint(4)),
+    (0xDB1, 12, "JMPXARGS", Args::Uint(4)),
+    (0xDB2, 12, "RETARGS", Args::Uint(4)),
+    (0xDB30, 16, "RET", Args::None),
+    (0xDB31, 16, "RETALT", Args::None),
+    (0xDB32, 16, "RETBOOL", Args::None),
+    (0xDB34, 16, "CALLCC", Args::None),
+    (0xDB35, 16, "JMPXDATA", Args::None),
+    (0xDB36, 16, "CALLCCARGS", Args::Pair(0, 0)),
+    (0xDB38, 16, "CALLXVARARGS", Args::None),
+    (0xDB39, 16, "RETVARARGS", Args::None),
+    (0xDB3A, 16, "JMPXVARARGS", Args::None),
+    (0xDB3B, 16, "CALLCCVARARGS", Args::None),
+    (0xDB3C, 16, "CALLREF", Args::Code),
+    (0xDB3D, 16, "JMPREF", Args::Code),
+    (0xDB3E, 16, "JMPREFDATA", Args::Code),
+    (0xDB3F, 16, "RETDATA", Args::None),
+    (0xDC, 8, "IFRET", Args::None),
+    (0xDD, 8, "IFNOTRET", Args::None),
+    (0xDE, 8, "IF", Args::None),
+    (0xDF, 8, "IFNOT", Args::None),
+    (0xE0, 8, "IFJMP", Args::None),
+    (0xE1, 8, "IFNOTJMP", Args::None),
+    (0xE2, 8, "IFELSE", Args::None),
+    (0xE300, 16, "IFREF", Args::Code),
+    (0xE301, 16, "IFNOTREF", Args::Code),
+    (0xE302, 16, "IFJMPREF", Args::Code),
+    (0xE303, 16, "IFNOTJMPREF", Args::Code),
+    (0xE304, 16, "CONDSEL", Args::None),
+    (0xE305, 16, "CONDSELCHK", Args::None),
+    (0xE308, 16, "IFRETALT", Args::None),
+    (0xE309, 16, "IFNOTRETALT", Args::None),
+    (0xE30D, 16, "IFREFELSE", Args::Code),
+    (0xE30E, 16, "IFELSEREF", Args::Code),
+    (0xE30F, 16, "IFREFELSEREF", Args::Code2),
+    (0xE314, 16, "REPEATBRK", Args::None),
+    (0xE315, 16, "REPEATENDBRK", Args::None),
+    (0xE316, 16, "UNTILBRK", Args::None),
+    (0xE317, 16, "UNTILENDBRK", Args::None),
+    (0xE318, 16, "WHILEBRK", Args::None),
+    (0xE319, 16, "WHILEENDBRK", Args::None),
+    (0xE31A, 16, "AGAINBRK", Args::None),
+    (0xE31B, 16, "AGAINENDBRK", Args::None),
+    (0x71C, 11, "IFBITJMP", Args::Uint(5)),
+    (0x71D, 11, "IFNBITJMP", Args::Uint(5)),
+    (0xE4, 8, "REPEAT", Args::None),
+    (0xE5, 8, "REPEATEND", Args::None),
+    (0xE6, 8, "UNTIL", Args::None),
+    (0xE7, 8, "UNTILEND", Args::None),
+    (0xE8, 8, "WHILE", Args::None),
+    (0xE9, 8, "WHILEEND", Args::None),
+    (0xEA, 8, "AGAIN", Args::None),
+    (0xEB, 8, "AGAINEND", Args::None),
+    (0xEC, 8, "SETCONTARGS", Args::Pair(0, 0)),
+    (0xED0, 12, "RETURNARGS", Args::Uint(4)),
+    (0xED10, 16, "RETURNVARARGS", Args::None),
+    (0xED11, 16, "SETCONTVARARGS", Args::None),
+    (0xED12, 16, "SETNUMVARARGS", Args::None),
+    (0xED1E, 16, "BLESS", Args::None),
+    (0xED1F, 16, "BLESSVARARGS", Args::None),
+    (0xED4, 12, "PUSH", Args::Control),
+    (0xED5, 12, "POP", Args::Control),
+    (0xED6, 12, "SETCONT", Args::Control),
+    (0xED7, 12, "SETRETCTR", Args::Control),
+    (0xED8, 12, "SETALTCTR", Args::Control),
+    (0xED9, 12, "POPSAVE", Args::Control),
+    (0xEDA, 12, "SAVE", Args::Control),
+    (0xEDB, 12, "SAVEALT", Args::Control),
+    (0xEDC, 12, "SAVEBOTH", Args::Control),
+    (0xEDE0, 16, "PUSHCTRX", Args::None),
+    (0xEDE1, 16, "POPCTRX", Args::None),
+    (0xEDE2, 16, "SETCONTCTRX", Args::None),
+    (0xEDF0, 16, "BOOLAND", Args::None),
+    (0xEDF1, 16, "BOOLOR", Args::None),
+    (0xEDF2, 16, "COMPOSBOTH", Args::None),
+    (0xEDF3, 16, "ATEXIT", Args::None),
+    (0xEDF4, 16, "ATEXITALT", Args::None),
+    (0xEDF5, 16, "SETEXITALT", Args::None),
+    (0xEDF6, 16, "THENRET", Args::None),
+    (0xEDF7, 16, "THENRETALT", Args::None),
+    (0xEDF8, 16, "INVERT", Args::None),
+    (0xEDF9, 16, "BOOLEVAL", Args::None),
+    (0xEDFA, 16, "SAMEALT", Args::None),
+    (0xEDFB, 16, "SAMEALTSAVE", Args::None),
+    (0xEE, 8, "BLESSARGS", Args::Pair(0, 0)),
+    (0xF0, 8, "CALLDICT", Args::Uint(8)),
+    (0x3C4, 10, "CALLDICT", Args::Uint(14)),
+    (0x3C5, 10, "JMPDICT", Args::Uint(14)),
+    (0x3C6, 10, "PREPAREDICT", Args::Uint(14)),
+    // exceptions
+    (0x3C8, 10, "THROW", Args::Uint(6)),
+    (0x3C9, 10, "THROWIF", Args::Uint(6)),
+    (0x3CA, 10, "THROWIFNOT", Args::Uint(6)),
+    (0x1E58, 13, "THROW", Args::Uint(11)),
+    (0x1E59, 13, "THROWARG", Args::Uint(11)),
+    (0x1E5A, 13, "THROWIF", Args::Uint(11)),
+    (0x1E5B, 13, "THROWARGIF", Args::Uint(11)),
+    (0x1E5C, 13, "THROWIFNOT", Args::Uint(11)),
+    (0x1E5D, 13, "THROWARGIFNOT", Args::Uint(11)),
+    (0xF2F0, 16, "THROWANY", Args::None),
+    (0xF2F1, 16, "THROWARGANY", Args::None),
+    (0xF2F2, 16, "THROWANYIF", Args::None),
+    (0xF2F3, 16, "THROWARGANYIF", Args::None),
+    (0xF2F4, 16, "THROWANYIFNOT", Args::None),
+    (0xF2F5, 16, "THROWARGANYIFNOT", Args::None),
+    (0xF2FF, 16, "TRY", Args::None),
+    (0xF3, 8, "TRYARGS", Args::Pair(0, 0)),
+    // dictionaries
+    (0xF400, 16, "STDICT", Args::None),
+    (0xF401, 16, "SKIPDICT", Args::None),
+    (0xF402, 16, "LDDICTS", Args::None),
+    (0xF403, 16, "PLDDICTS", Args::None),
+    (0xF404, 16, "LDDICT", Args::None),
+    (0xF405, 16, "PLDDICT", Args::None),
+    (0xF406, 16, "LDDICTQ", Args::None),
+    (0xF407, 16, "PLDDICTQ", Args::None),
+    (0xF40A, 16, "DICTGET", Args::None),
+    (0xF40B, 16, "DICTGETREF", Args::None),
+    (0xF40C, 16, "DICTIGET", Args::None),
+    (0xF40D, 16, "DICTIGETREF", Args::None),
+    (0xF40E, 16, "DICTUGET", Args::None),
+    (0xF40F, 16, "DICTUGETREF", Args::None),
+    (0xF412, 16, "DICTSET", Args::None),
+    (0xF413, 16, "DICTSETREF", Args::None),
+    (0xF414, 16, "DICTISET", Args::None),
+    (0xF415, 16, "DICTISETREF", Args::None),
+    (0xF416, 16, "DICTUSET", Args::None),
+    (0xF417, 16, "DICTUSETREF", Args::None),
+    (0xF41A, 16, "DICTSETGET", Args::None),
+    (0xF41B, 16, "DICTSETGETREF", Args::None),
+    (0xF41C, 16, "DICTISETGET", Args::None),
+    (0xF41D, 16, "DICTISETGETREF", Args::None),
+    (0xF41E, 16, "DICTUSETGET", Args::None),
+    (0xF41F, 16, "DICTUSETGETREF", Args::None),
+    (0xF422, 16, "DICTREPLACE", Args::None),
+    (0xF423, 16, "DICTREPLACEREF", Args::None),
+    (0xF424, 16, "DICTIREPLACE", Args::None),
+    (0xF425, 16, "DICTIREPLACEREF", Args::None),
+    (0xF426, 16, "DICTUREPLACE", Args::None),
+    (0xF427, 16, "DICTUREPLACEREF", Args::None),
+    (0xF432, 16, "DICTADD", Args::None),
+    (0xF433, 16, "DICTADDREF", Args::None),
+    (0xF434, 16, "DICTIADD", Args::None),
+    (0xF435, 16, "DICTIADDREF", Args::None),
+    (0xF436, 16, "DICTUADD", Args::None),
+    (0xF437, 16, "DICTUADDREF", Args::None),
+    (0xF441, 16, "DICTSETB", Args::None),
+    (0xF442, 16, "DICTISETB", Args::None),
+    (0xF443, 16, "DICTUSETB", Args::None),
+    (0xF459, 16, "DICTDEL", Args::None),
+    (0xF45A, 16, "DICTIDEL", Args::None),
+    (0xF45B, 16, "DICTUDEL", Args::None),
+    (0xF469, 16, "DICTGETOPTREF", Args::None),
+    (0xF46A, 16, "DICTIGETOPTREF", Args::None),
+    (0xF46B, 16, "DICTUGETOPTREF", Args::None),
+    (0xF46D, 16, "DICTSETGETOPTREF", Args::None),
+    (0xF46E, 16, "DICTISETGETOPTREF", Args::None),
+    (0xF46F, 16, "DICTUSETGETOPTREF", Args::None),
+    (0xF474, 16, "DICTGETNEXT", Args::None),
+    (0xF475, 16, "DICTGETNEXTEQ", Args::None),
+    (0xF476, 16, "DICTGETPREV", Args::None),
+    (0xF477, 16, "DICTGETPREVEQ", Args::None),
+    (0xF478, 16, "DICTIGETNEXT", Args::None),
+    (0xF479, 16, "DICTIGETNEXTEQ", Args::None),
+    (0xF47A, 16, "DICTIGETPREV", Args::None),
+    (0xF47B, 16, "DICTIGETPREVEQ", Args::None),
+    (0xF47C, 16, "DICTUGETNEXT", Args::None),
+    (0xF47D, 16, "DICTUGETNEXTEQ", Args::None),
+    (0xF47E, 16, "DICTUGETPREV", Args::None),
+    (0xF47F, 16, "DICTUGETPREVEQ", Args::None),
+    (0xF482, 16, "DICTMIN", Args::None),
+    (0xF483, 16, "DICTMINREF", Args::None),
+    (0xF484, 16, "DICTIMIN", Args::None),
+    (0xF485, 16, "DICTIMINREF", Args::None),
+    (0xF486, 16, "DICTUMIN", Args::None),
+    (0xF487, 16, "DICTUMINREF", Args::None),
+    (0xF48A, 16, "DICTMAX", Args::None),
+    (0xF48B, 16, "DICTMAXREF", Args::None),
+    (0xF48C, 16, "DICTIMAX", Args::None),
+    (0xF48D, 16, "DICTIMAXREF", Args::None),
+    (0xF48E, 16, "DICTUMAX", Args::None),
+    (0xF48F, 16, "DICTUMAXREF", Args::None),
+    (0xF4A0, 16, "DICTIGETJMP", Args::None),
+    (0xF4A1, 16, "DICTUGETJMP", Args::None),
+    (0xF4A2, 16, "DICTIGETEXEC", Args::None),
+    (0xF4A3, 16, "DICTUGETEXEC", Args::None),
+    (0x3D29, 14, "DICTPUSHCONST", Args::Special(Special::DictPushConst)),
+    (0xF4A8, 16, "PFXDICTGETQ", Args::None),
+    (0xF4A9, 16, "PFXDICTGET", Args::None),
+    (0xF4AA, 16, "PFXDICTGETJMP", Args::None),
+    (0xF4AB, 16, "PFXDICTGETEXEC", Args::None),
+    (0xF4BC, 16, "DICTIGETJMPZ", Args::None),
+    (0xF4BD, 16, "DICTUGETJMPZ", Args::None),
+    (0xF4BE, 16, "DICTIGETEXECZ", Args::None),
+    (0xF4BF, 16, "DICTUGETEXECZ", Args::None),
+    // blockchain specific
+    (0xF800, 16, "ACCEPT", Args::None),
+    (0xF801, 16, "SETGASLIMIT", Args::None),
+    (0xF802, 16, "BUYGAS", Args::None),
+    (0xF804, 16, "GRAMTOGAS", Args::None),
+    (0xF805, 16, "GASTOGRAM", Args::None),
+    (0xF80F, 16, "COMMIT", Args::None),
+    (0xF810, 16, "RANDU256", Args::None),
+    (0xF811, 16, "RAND", Args::None),
+    (0xF814, 16, "SETRAND", Args::None),
+    (0xF815, 16, "ADDRAND", Args::None),
+    (0xF823, 16, "NOW", Args::None),
+    (0xF824, 16, "BLOCKLT", Args::None),
+    (0xF825, 16, "LTIME", Args::None),
+    (0xF826, 16, "RANDSEED", Args::None),
+    (0xF827, 16, "BALANCE", Args::None),
+    (0xF828, 16, "MYADDR", Args::None),
+    (0xF829, 16, "CONFIGROOT", Args::None),
+    (0xF82, 12, "GETPARAM", Args::Uint(4)),
+    (0xF830, 16, "CONFIGDICT", Args::None),
+    (0xF832, 16, "CONFIGPARAM", Args::None),
+    (0xF833, 16, "CONFIGOPTPARAM", Args::None),
+    (0xF840, 16, "GETGLOBVAR", Args::None),
+    (0x7C2, 11, "GETGLOB", Args::Uint(5)),
+    (0xF860, 16, "SETGLOBVAR", Args::None),
+    (0x7C3, 11, "SETGLOB", Args::Uint(5)),
+    (0xF900, 16, "HASHCU", Args::None),
+    (0xF901, 16, "HASHSU", Args::None),
+    (0xF902, 16, "SHA256U", Args::None),
+    (0xF910, 16, "CHKSIGNU", Args::None),
+    (0xF911, 16, "CHKSIGNS", Args::None),
+    (0xF940, 16, "CDATASIZEQ", Args::None),
+    (0xF941, 16, "CDATASIZE", Args::None),
+    (0xF942, 16, "SDATASIZEQ", Args::None),
+    (0xF943, 16, "SDATASIZE", Args::None),
+    (0xFA00, 16, "LDGRAMS", Args::None),
+    (0xFA01, 16, "LDVARINT16", Args::None),
+    (0xFA02, 16, "STGRAMS", Args::None),
+    (0xFA03, 16, "STVARINT16", Args::None),
+    (0xFA04, 16, "LDVARUINT32", Args::None),
+    (0xFA05, 16, "LDVARINT32", Args::None),
+    (0xFA06, 16, "STVARUINT32", Args::None),
+    (0xFA07, 16, "STVARINT32", Args::None),
+    (0xFA40, 16, "LDMSGADDR", Args::None),
+    (0xFA41, 16, "LDMSGADDRQ", Args::None),
+    (0xFA42, 16, "PARSEMSGADDR", Args::None),
+    (0xFA43, 16, "PARSEMSGADDRQ", Args::None),
+    (0xFA44, 16, "REWRITESTDADDR", Args::None),
+    (0xFA45, 16, "REWRITESTDADDRQ", Args::None),
+    (0xFA46, 16, "REWRITEVARADDR", Args::None),
+    (0xFA47, 16, "REWRITEVARADDRQ", Args::None),
+    (0xFB00, 16, "SENDRAWMSG", Args::None),
+    (0xFB02, 16, "RAWRESERVE", Args::None),
+    (0xFB03, 16, "RAWRESERVEX", Args::None),
+    (0xFB04, 16, "SETCODE", Args::None),
+    (0xFB06, 16, "SETLIBCODE", Args::None),
+    (0xFB07, 16, "CHANGELIB", Args::None),
+    (0xFE, 8, "DEBUG", Args::Uint(8)),
+    (0xFF00, 16, "SETCP0", Args::None),
+    (0xFFF0, 16, "SETCPX", Args::None),
+    (0xFF, 8, "SETCP", Args::Uint(8)),
+];
@@ -1,9 +1,9 @@
 use ethabi::{Token as EthTokenValue, Token};
 use num_bigint::{BigInt, BigUint};
 use serde::Deserialize;
-use ton_abi::{Event as AbiEvent, Token as TonToken, TokenValue as TonTokenValue};
+use ton_abi::{Event as AbiEvent, ParamType, Token as TonToken, TokenValue as TonTokenValue};
 use ton_block::MsgAddressInt;
-use ton_types::{Cell, UInt256};
+use ton_types::{BuilderData, Cell, UInt256};
 
 use wasm_bindgen::prelude::*;
 
@@ -22,6 +22,54 @@ pub struct EthPayload {
 }
 
 pub fn encode_eth_payload(event: EthPayload, event_abi: &str) -> Result<Vec<u8>> {
+    let (configuration_workchain_id, configuration_address) = pack_configuration_address(&event.event_configuration)?;
+
+    let decoded = decode_event_data(event.event_data, event_abi)?;
+    let event_data = map_event_data(decoded)?;
+
+    let tuple = EthTokenValue::Tuple(vec![
+        event.event_transaction.pack(),
+        event.event_transaction_lt.pack(),
+        event.event_timestamp.pack(),
+        event.event_index.pack(),
+        event_data.pack(),
+        configuration_workchain_id.pack(),
+        configuration_address.pack(),
+        BigUint::from(event.required_confirmations).pack(),
+        BigUint::from(event.required_rejections).pack(),
+        event.proxy.pack(),
+    ]);
+
+    Ok(ethabi::encode(&[tuple]).to_vec())
+}
+
+/// Splits configuration address into `int8` workchain id and `uint256` address as expected by Ethereum contracts
+fn pack_configuration_address(address: &MsgAddressInt) -> Result<(i8, UInt256)> {
+    let (workchain_id, address) = match address {
+        MsgAddressInt::AddrStd(address) if address.anycast.is_none() => {
+            (address.workchain_id as i32, address.address.clone())
+        }
+        MsgAddressInt::AddrVar(address) if address.anycast.is_none() => (address.workchain_id, address.address.clone()),
+        _ => return Err("Anycast configuration addresses are not supported"),
+    };
+
+    if workchain_id < i8::MIN as i32 || workchain_id > i8::MAX as i32 {
+        return Err("Workchain id is out of range");
+    }
+    if address.remaining_bits() != 256 {
+        return Err("Configuration address must be 256 bits long");
+    }
+
+    Ok((workchain_id as i8, UInt256::from(address.get_bytestring(0))))
+}
+
+pub fn decode_event_data(event_data: Cell, event_abi: &str) -> Result<Vec<TonToken>> {
+    parse_event_abi(event_abi)?
+        .decode_input(event_data.into())
+        .map_err(|_| "Failed to decode TON event data")
+}
+
+pub fn parse_event_abi(event_abi: &str) -> Result<AbiEvent> {
     let event_abi =
         serde_json::from_str::<SwapBackEventAbi>(event_abi).map_err(|_| "Failed to parse swapback event abi")?;
     let mut abi = AbiEvent {
@@ -35,27 +83,46 @@ pub fn encode_eth_payload(event: EthPayload, event_abi: &str) -> Result<Vec<u8>>
     } else {
         abi.get_function_id() & 0x7FFFFFFF
     };
+    Ok(abi)
+}
 
-    let decoded = abi
-        .decode_input(event.event_data.into())
-        .map_err(|_| "Failed to decode TON event data")?;
+/// Recovers the Ethereum address which signed `payload` with `eth_sign`, i.e. the signed message
+/// is `keccak256("\x19Ethereum Signed Message:\n32" || keccak256(payload))`
+pub fn recover_signer(payload: &[u8], signature: &[u8]) -> Result<ethabi::Address> {
+    if signature.len() != 65 {
+        return Err("Invalid signature length");
+    }
 
-    let event_data = map_event_data(decoded)?;
+    let recovery_id = match signature[64] {
+        v @ 0..=1 => v,
+        v @ 27..=28 => v - 27,
+        _ => return Err("Invalid signature recovery id"),
+    };
+    let recovery_id = secp256k1::RecoveryId::parse(recovery_id).map_err(|_| "Invalid signature recovery id")?;
+    let signature = secp256k1::Signature::parse_slice(&signature[..64]).map_err(|_| "Invalid signature")?;
 
-    let tuple = EthTokenValue::Tuple(vec![
-        event.event_transaction.pack(),
-        event.event_transaction_lt.pack(),
-        event.event_timestamp.pack(),
-        event.event_index.pack(),
-        event_data.pack(),
-        (event.event_configuration.workchain_id() as i8).pack(),
-        UInt256::from(event.event_configuration.address().get_bytestring(0)).pack(),
-        BigUint::from(event.required_confirmations).pack(),
-        BigUint::from(event.required_rejections).pack(),
-        event.proxy.pack(),
-    ]);
+    let message = secp256k1::Message::parse(&eth_signed_message_hash(payload));
+    let public_key = secp256k1::recover(&message, &signature, &recovery_id).map_err(|_| "Failed to recover signer")?;
 
-    Ok(ethabi::encode(&[tuple]).to_vec())
+    // skip public key tag byte
+    let hash = keccak256(&public_key.serialize()[1..]);
+    Ok(ethabi::Address::from_slice(&hash[12..]))
+}
+
+fn eth_signed_message_hash(payload: &[u8]) -> [u8; 32] {
+    let mut message = b"\x19Ethereum Signed Message:\n32".to_vec();
+    message.extend_from_slice(&keccak256(payload));
+    keccak256(&message)
+}
+
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    use tiny_keccak::Hasher;
+
+    let mut keccak = tiny_keccak::Keccak::v256();
+    let mut output = [0; 32];
+    keccak.update(data);
+    keccak.finalize(&mut output);
+    output
 }
 
 pub fn map_event_data(tokens: Vec<TonToken>) -> Result<Vec<u8>> {
@@ -100,6 +167,106 @@ fn map_ton_to_eth(token: TonTokenValue) -> Result<EthTokenValue> {
     })
 }
 
+pub fn encode_event_data_from_log(
+    topics: Vec<ethabi::Hash>,
+    data: Vec<u8>,
+    eth_event_abi: &str,
+    ton_event_abi: &str,
+) -> Result<Cell> {
+    let eth_event_abi =
+        serde_json::from_str::<ethabi::Event>(eth_event_abi).map_err(|_| "Failed to parse ethereum event abi")?;
+    let ton_event_abi = parse_event_abi(ton_event_abi)?;
+
+    let log = eth_event_abi
+        .parse_log(ethabi::RawLog { topics, data })
+        .map_err(|_| "Failed to decode ethereum log")?;
+    let tokens = map_eth_to_ton(
+        log.params.into_iter().map(|param| param.value).collect(),
+        &ton_event_abi.inputs,
+    )?;
+
+    // event data starts with the event id, the same way `decode_event_data` expects it
+    let mut id = BuilderData::new();
+    id.append_u32(ton_event_abi.id)
+        .map_err(|_| "Failed to pack event data")?;
+
+    TonTokenValue::pack_values_into_chain(&tokens, vec![id], 2)
+        .and_then(|data| data.into_cell())
+        .map_err(|_| "Failed to pack event data")
+}
+
+pub fn map_eth_to_ton(tokens: Vec<EthTokenValue>, params: &[ton_abi::Param]) -> Result<Vec<TonToken>> {
+    if tokens.len() != params.len() {
+        return Err("Ethereum event doesn't match TON event abi");
+    }
+
+    tokens
+        .into_iter()
+        .zip(params.iter())
+        .map(|(token, param)| {
+            Ok(TonToken {
+                name: param.name.clone(),
+                value: map_eth_to_ton_value(token, &param.kind)?,
+            })
+        })
+        .collect()
+}
+
+fn map_eth_to_ton_value(token: EthTokenValue, kind: &ParamType) -> Result<TonTokenValue> {
+    Ok(match (token, kind) {
+        (EthTokenValue::FixedBytes(bytes), ParamType::FixedBytes(size)) if bytes.len() == *size => {
+            TonTokenValue::FixedBytes(bytes)
+        }
+        (EthTokenValue::Bytes(bytes), ParamType::Bytes) => TonTokenValue::Bytes(bytes),
+        (EthTokenValue::String(string), ParamType::Bytes) => TonTokenValue::Bytes(string.into_bytes()),
+        (EthTokenValue::Uint(value), ParamType::Uint(size)) => {
+            let mut bytes = [0; 32];
+            value.to_little_endian(&mut bytes);
+            let number = BigUint::from_bytes_le(&bytes);
+            if number.bits() > *size {
+                return Err("Uint value out of range");
+            }
+            TonTokenValue::Uint(ton_abi::Uint { number, size: *size })
+        }
+        (EthTokenValue::Address(address), ParamType::Uint(size)) if *size >= 160 => {
+            TonTokenValue::Uint(ton_abi::Uint {
+                number: BigUint::from_bytes_be(address.as_bytes()),
+                size: *size,
+            })
+        }
+        (EthTokenValue::Int(_), ParamType::Int(0)) => return Err("Invalid int size"),
+        (EthTokenValue::Int(value), ParamType::Int(size)) => {
+            let mut bytes = [0; 32];
+            value.to_little_endian(&mut bytes);
+            let number = BigInt::from_signed_bytes_le(&bytes);
+            let bound = BigInt::from(1) << (*size - 1);
+            if number < -bound.clone() || number >= bound {
+                return Err("Int value out of range");
+            }
+            TonTokenValue::Int(ton_abi::Int { number, size: *size })
+        }
+        (EthTokenValue::Bool(value), ParamType::Bool) => TonTokenValue::Bool(value),
+        (EthTokenValue::FixedArray(tokens), ParamType::FixedArray(kind, size)) if tokens.len() == *size => {
+            TonTokenValue::FixedArray(
+                tokens
+                    .into_iter()
+                    .map(|token| map_eth_to_ton_value(token, kind))
+                    .collect::<Result<_, _>>()?,
+            )
+        }
+        (EthTokenValue::Array(tokens), ParamType::Array(kind)) => TonTokenValue::Array(
+            tokens
+                .into_iter()
+                .map(|token| map_eth_to_ton_value(token, kind))
+                .collect::<Result<_, _>>()?,
+        ),
+        (EthTokenValue::Tuple(tokens), ParamType::Tuple(params)) => {
+            TonTokenValue::Tuple(map_eth_to_ton(tokens, params)?)
+        }
+        _ => return Err("Unsupported type"),
+    })
+}
+
 #[derive(Debug, Clone, Deserialize)]
 struct SwapBackEventAbi {
     name: String,
@@ -0,0 +1,44 @@
+use ton_abi::Contract;
+use ton_types::Cell;
+
+use crate::disasm;
+use crate::utils::Result;
+
+#[derive(Debug, Clone)]
+pub struct CodeFunctions {
+    pub found: Vec<KnownFunction>,
+    pub missing: Vec<KnownFunction>,
+    pub unknown: Vec<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KnownFunction {
+    pub name: String,
+    pub id: u32,
+}
+
+pub fn list_code_functions(code: &Cell, abis: &[Contract]) -> Result<CodeFunctions> {
+    let function_ids = disasm::find_function_ids(code)?;
+
+    // the built-in `getDetails` label is not reported, only functions of the passed ABIs are
+    let mut known = disasm::abi_functions(abis)
+        .into_iter()
+        .map(|(id, name)| KnownFunction { name, id })
+        .collect::<Vec<_>>();
+    known.sort_by_key(|function| function.id);
+
+    let (found, missing) = known
+        .into_iter()
+        .partition::<Vec<_>, _>(|function| function_ids.contains(&function.id));
+
+    let unknown = function_ids
+        .into_iter()
+        .filter(|id| !found.iter().any(|function| function.id == *id))
+        .collect();
+
+    Ok(CodeFunctions {
+        found,
+        missing,
+        unknown,
+    })
+}
@@ -0,0 +1,173 @@
+use ton_abi::{Contract, ParamType};
+use ton_block::{CommonMsgInfo, MsgAddressInt, Transaction, TransactionDescr};
+
+use crate::contract::{EventStatus, TryParse};
+use crate::utils::Result;
+
+/// Names of the event contract functions which are counted as votes
+#[derive(Debug, Clone)]
+pub struct VoteFunctions {
+    pub deploy: String,
+    pub confirm: String,
+    pub reject: String,
+}
+
+impl Default for VoteFunctions {
+    fn default() -> Self {
+        Self {
+            deploy: "constructor".to_owned(),
+            confirm: "confirm".to_owned(),
+            reject: "reject".to_owned(),
+        }
+    }
+}
+
+/// Vote function from the contract ABI with the position of its relay address input
+struct VoteFunction {
+    name: String,
+    kind: VoteKind,
+    relay_index: usize,
+}
+
+fn resolve_vote_functions(contract_abi: &Contract, functions: &VoteFunctions) -> Result<Vec<VoteFunction>> {
+    let resolve = |name: &str, kind: VoteKind| {
+        let function = contract_abi.function(name).ok()?;
+        let relay_index = function
+            .inputs
+            .iter()
+            .position(|param| param.kind == ParamType::Address)?;
+        Some(VoteFunction {
+            name: name.to_owned(),
+            kind,
+            relay_index,
+        })
+    };
+
+    let confirm = resolve(&functions.confirm, VoteKind::Confirm).ok_or("Contract abi doesn't have confirm function")?;
+    let reject = resolve(&functions.reject, VoteKind::Reject).ok_or("Contract abi doesn't have reject function")?;
+
+    // contracts without relay in the constructor are deployed by someone else
+    let mut result = vec![confirm, reject];
+    result.extend(resolve(&functions.deploy, VoteKind::Deploy));
+    Ok(result)
+}
+
+pub fn build_vote_timeline(
+    transactions: &[Transaction],
+    event_address: &MsgAddressInt,
+    contract_abi: &Contract,
+    functions: &VoteFunctions,
+    required_confirmations: u16,
+    required_rejections: u16,
+) -> Result<Vec<Vote>> {
+    let functions = resolve_vote_functions(contract_abi, functions)?;
+
+    let mut status = EventStatus::InProcess;
+    let mut confirmations = 0;
+    let mut rejections = 0;
+    let mut voted = Vec::new();
+
+    let mut votes = Vec::new();
+    for transaction in transactions {
+        if transaction.account_id() != &event_address.address() {
+            return Err("Transaction belongs to another account");
+        }
+
+        if is_aborted(transaction)? {
+            continue;
+        }
+
+        let message = match transaction
+            .read_in_msg()
+            .map_err(|_| "Failed to read inbound message")?
+        {
+            Some(message) if matches!(message.header(), CommonMsgInfo::IntMsgInfo(_)) => message,
+            _ => continue,
+        };
+
+        let body = match message.body() {
+            Some(body) => body,
+            None => continue,
+        };
+
+        let decoded = match contract_abi.decode_input(body, true) {
+            Ok(decoded) => decoded,
+            Err(_) => continue,
+        };
+
+        let function = match functions.iter().find(|function| function.name == decoded.function_name) {
+            Some(function) => function,
+            None => continue,
+        };
+
+        // calls without a valid relay are not votes, they don't affect the counters either
+        let relay = match decoded.tokens.into_iter().nth(function.relay_index) {
+            Some(token) => match TryParse::<MsgAddressInt>::try_parse(token) {
+                Ok(relay) => relay,
+                Err(_) => continue,
+            },
+            None => continue,
+        };
+
+        // only the first vote of each relay is counted
+        if voted.contains(&relay) {
+            continue;
+        }
+        voted.push(relay.clone());
+
+        let kind = function.kind;
+        match kind {
+            VoteKind::Deploy | VoteKind::Confirm => confirmations += 1,
+            VoteKind::Reject => rejections += 1,
+        }
+
+        if status == EventStatus::InProcess {
+            if confirmations >= required_confirmations {
+                status = EventStatus::Confirmed;
+            } else if rejections >= required_rejections {
+                status = EventStatus::Rejected;
+            }
+        }
+
+        votes.push(Vote {
+            relay,
+            kind,
+            lt: transaction.logical_time(),
+            timestamp: transaction.now(),
+            status,
+            confirmations,
+            rejections,
+        });
+    }
+
+    Ok(votes)
+}
+
+fn is_aborted(transaction: &Transaction) -> Result<bool> {
+    match transaction
+        .read_description()
+        .map_err(|_| "Failed to read transaction description")?
+    {
+        TransactionDescr::Ordinary(description) => Ok(description.aborted),
+        _ => Ok(false),
+    }
+}
+
+pub struct Vote {
+    pub relay: MsgAddressInt,
+    pub kind: VoteKind,
+    pub lt: u64,
+    pub timestamp: u32,
+    /// Event status after this vote
+    pub status: EventStatus,
+    pub confirmations: u16,
+    pub rejections: u16,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum VoteKind {
+    /// Event contract deployment by the first relay, counted as a confirmation
+    Deploy,
+    Confirm,
+    Reject,
+}
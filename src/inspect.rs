@@ -0,0 +1,103 @@
+use std::collections::{HashMap, HashSet};
+
+use serde_json::{json, Value};
+use ton_types::Cell;
+
+use crate::utils::Result;
+
+pub fn inspect_boc(boc: &[u8], max_depth: Option<usize>) -> Result<Value> {
+    let cell =
+        ton_types::deserialize_tree_of_cells(&mut std::io::Cursor::new(boc)).map_err(|_| "Failed to parse Cell")?;
+    inspect_cell(&cell, max_depth)
+}
+
+/// Statistics are computed over the whole cells tree, regardless of `max_depth`:
+/// `total_cells` counts every reference as a separate cell, `unique_cells` counts distinct cells
+/// and `duplicates` maps hashes of cells with several parents to the number of references to them
+pub fn inspect_cell(cell: &Cell, max_depth: Option<usize>) -> Result<Value> {
+    let mut inspector = Inspector {
+        max_depth,
+        visited: HashSet::new(),
+    };
+    let root = inspector.visit(cell, 0)?;
+
+    let mut stats = Stats::default();
+    let total_cells = stats.count(cell)?;
+    let unique_cells = stats.subtree_sizes.len();
+    let duplicates = stats
+        .references
+        .into_iter()
+        .filter(|(_, count)| *count > 1)
+        .map(|(hash, count)| (hash, Value::from(count)))
+        .collect::<serde_json::Map<_, _>>();
+
+    Ok(json!({
+        "root": root,
+        "total_cells": total_cells,
+        "unique_cells": unique_cells,
+        "duplicates": duplicates,
+    }))
+}
+
+#[derive(Default)]
+struct Stats {
+    subtree_sizes: HashMap<String, u64>,
+    references: HashMap<String, usize>,
+}
+
+impl Stats {
+    fn count(&mut self, cell: &Cell) -> Result<u64> {
+        let hash = cell.repr_hash().to_hex_string();
+        if let Some(size) = self.subtree_sizes.get(&hash) {
+            return Ok(*size);
+        }
+
+        let mut size = 1u64;
+        for i in 0..cell.references_count() {
+            let child = cell.reference(i).map_err(|_| "Failed to read cell reference")?;
+            *self.references.entry(child.repr_hash().to_hex_string()).or_default() += 1;
+            // the expanded tree of a DAG can be exponentially large
+            size = size.saturating_add(self.count(&child)?);
+        }
+
+        self.subtree_sizes.insert(hash, size);
+        Ok(size)
+    }
+}
+
+struct Inspector {
+    max_depth: Option<usize>,
+    visited: HashSet<String>,
+}
+
+impl Inspector {
+    fn visit(&mut self, cell: &Cell, depth: usize) -> Result<Value> {
+        let hash = cell.repr_hash().to_hex_string();
+
+        let duplicate = !self.visited.insert(hash.clone());
+
+        let mut node = json!({
+            "hash": hash,
+            "depth": cell.repr_depth(),
+            "bit_length": cell.bit_length(),
+            "data": cell.to_hex_string(),
+            "cell_type": format!("{:?}", cell.cell_type()),
+            "level_mask": cell.level_mask().mask(),
+            "duplicate": duplicate,
+        });
+
+        let mut refs = Vec::with_capacity(cell.references_count());
+        for i in 0..cell.references_count() {
+            let child = cell.reference(i).map_err(|_| "Failed to read cell reference")?;
+            // subtrees of duplicate cells are already listed, so only hashes are shown
+            if duplicate || matches!(self.max_depth, Some(max_depth) if depth >= max_depth) {
+                refs.push(json!({ "hash": child.repr_hash().to_hex_string(), "truncated": true }));
+            } else {
+                refs.push(self.visit(&child, depth + 1)?);
+            }
+        }
+        node["refs"] = Value::Array(refs);
+
+        Ok(node)
+    }
+}
@@ -1,34 +1,508 @@
+pub mod address;
+pub mod authenticity;
+pub mod bridge;
+pub mod configuration;
 pub mod contract;
+pub mod decode;
+pub mod disasm;
 pub mod eth;
+pub mod functions;
+pub mod history;
+pub mod inspect;
+pub mod quorum;
+pub mod registry;
+pub mod shard;
+pub mod signature;
+pub mod summary;
 pub mod tvm;
 mod utils;
-
-use std::str::FromStr;
+pub mod verify;
 
 use num_bigint::BigUint;
 use num_traits::ToPrimitive;
-use ton_block::MsgAddressInt;
+use ton_block::{Deserializable, MsgAddressInt};
 use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
 
 use crate::utils::*;
 
+#[wasm_bindgen(typescript_custom_section)]
+const TYPESCRIPT_TYPES: &str = r#"
+/** Error thrown by all functions of this package */
+export interface TonEventDetailsError extends Error {
+    name: 'TonEventDetailsError';
+}
+
+/** Raw (`0:...`) or user friendly (`EQ...`) TON address */
+export type TonAddress = string;
+/** `0x` prefixed EIP-55 checksummed Ethereum address */
+export type EthAddress = string;
+/** Base64 encoded bag of cells */
+export type Boc = string;
+/** Base64 or hex encoded string, or raw bytes of the bag of cells */
+export type BocInput = Boc | Uint8Array;
+
+/** Plain object alternative to `AddressFormat`, addresses are raw unless `userFriendly` is set */
+export interface AddressFormatOptions {
+    userFriendly?: boolean;
+    bounceable?: boolean;
+    urlSafe?: boolean;
+    testnet?: boolean;
+}
+
+/** Names of the event contract functions counted as votes, defaults to `constructor`, `confirm` and `reject` */
+export interface VoteFunctions {
+    deploy?: string;
+    confirm?: string;
+    reject?: string;
+}
+
+export interface DecodedBody {
+    kind: 'call' | 'output' | 'event';
+    name: string;
+    tokens: DecodedEventData;
+}
+
+export interface DecodedMessage {
+    hash: string;
+    type: 'internal' | 'external_in' | 'external_out';
+    src: TonAddress | null;
+    dst: TonAddress | null;
+    body: DecodedBody | null;
+}
+
+export interface DecodedTransaction {
+    hash: string;
+    lt: bigint;
+    now: number;
+    in_msg: DecodedMessage | null;
+    out_msgs: DecodedMessage[];
+    events: DecodedMessage[];
+}
+
+/** Called for each matching account and with `error` for each account that fails to decode */
+export type ShardStateCallback = (
+    address: TonAddress,
+    details: TonEventDetails | null,
+    error: TonEventDetailsError | null,
+) => boolean | void;
+
+export type DecodedTokenValue = bigint | boolean | string | null | DecodedTokenValue[] | DecodedEventData;
+export type DecodedEventData = { [name: string]: DecodedTokenValue };
+
+export interface TokenBurnEventData extends DecodedEventData {
+    wid: bigint;
+    addr: bigint;
+    tokens: bigint;
+    ethereum_address: bigint;
+}
+
+/** Decoded event data of the known event kind */
+export type EventDataOf<K extends EventKind> = {
+    token_burn: TokenBurnEventData;
+}[K];
+
+export interface TonEventInitDataJSON {
+    eventTransaction: string;
+    eventTransactionLt: string | number;
+    eventTimestamp: number;
+    eventIndex: number;
+    eventData: Boc;
+    tonEventConfiguration: TonAddress;
+    requiredConfirmations: number;
+    requiredRejections: number;
+    configurationMeta: Boc;
+}
+
+export interface TonEventDetailsJSON {
+    initData: TonEventInitDataJSON;
+    status: EventStatus;
+    confirmations: TonAddress[];
+    rejections: TonAddress[];
+    /** Hex encoded signatures as stored by the relays, see `TonEventDetails.signatures` for parsed values */
+    signatures: string[];
+}
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "string[]")]
+    pub type StringArray;
+
+    #[wasm_bindgen(typescript_type = "TonAddress[]")]
+    pub type TonAddressArray;
+
+    #[wasm_bindgen(typescript_type = "number[]")]
+    pub type NumberArray;
+
+    #[wasm_bindgen(typescript_type = "Signature[]")]
+    pub type SignatureArray;
+
+    #[wasm_bindgen(typescript_type = "Vote[]")]
+    pub type VoteArray;
+
+    #[wasm_bindgen(typescript_type = "BridgeRelay[]")]
+    pub type BridgeRelayArray;
+
+    #[wasm_bindgen(typescript_type = "CodeFunction[]")]
+    pub type CodeFunctionArray;
+
+    #[wasm_bindgen(typescript_type = "BocInput")]
+    pub type BocInput;
+
+    #[wasm_bindgen(typescript_type = "BocInput[]")]
+    pub type BocInputArray;
+
+    #[wasm_bindgen(typescript_type = "ShardStateCallback")]
+    pub type ShardStateCallback;
+
+    #[wasm_bindgen(typescript_type = "AddressFormat | AddressFormatOptions")]
+    pub type AddressFormatInput;
+
+    #[wasm_bindgen(typescript_type = "VoteFunctions")]
+    pub type VoteFunctionsInput;
+
+    #[wasm_bindgen(typescript_type = "DecodedEventData")]
+    pub type DecodedEventDataObject;
+
+    #[wasm_bindgen(typescript_type = "DecodedTransaction")]
+    pub type DecodedTransactionObject;
+
+    #[wasm_bindgen(typescript_type = "DecodedMessage")]
+    pub type DecodedMessageObject;
+
+    #[wasm_bindgen(typescript_type = "TonEventInitDataJSON")]
+    pub type TonEventInitDataJsonObject;
+
+    #[wasm_bindgen(typescript_type = "TonEventDetailsJSON")]
+    pub type TonEventDetailsJsonObject;
+}
+
+#[wasm_bindgen(js_name = "isTonEventDetailsError")]
+pub fn is_ton_event_details_error(error: &JsValue) -> bool {
+    error
+        .dyn_ref::<js_sys::Error>()
+        .map(|error| error.name() == "TonEventDetailsError")
+        .unwrap_or_default()
+}
+
 #[global_allocator]
 static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
 
 #[wasm_bindgen(js_name = "getDetails")]
-pub fn get_details(account_state: &str) -> Result<TonEventDetails, JsValue> {
+pub fn get_details(account_state: &BocInput, format: Option<AddressFormatInput>) -> Result<TonEventDetails, JsValue> {
     utils::set_panic_hook();
-    let account_state = base64::decode(account_state).map_err(|_| "Failed to decode account state")?;
+    let format = parse_address_format(format).handle_error()?;
+    let account_state = parse_boc_input(account_state)
+        .map_err(|_| "Failed to decode account state")
+        .handle_error()?;
     let (code, data) = utils::decode_account_state(&account_state).handle_error()?;
     let details = contract::get_details(code, data).handle_error()?;
-    convert_event_details(details).handle_error()
+    convert_event_details(details, format).handle_error()
+}
+
+#[wasm_bindgen(js_name = "getBridgeDetails")]
+pub fn get_bridge_details(
+    account_state: &BocInput,
+    bridge_abi: &str,
+    format: Option<AddressFormatInput>,
+) -> Result<BridgeDetails, JsValue> {
+    utils::set_panic_hook();
+    let format = parse_address_format(format).handle_error()?;
+    let account_state = parse_boc_input(account_state)
+        .map_err(|_| "Failed to decode account state")
+        .handle_error()?;
+    let (code, data) = utils::decode_account_state(&account_state).handle_error()?;
+    let bridge_abi = ton_abi::Contract::load(std::io::Cursor::new(bridge_abi))
+        .map_err(|_| "Failed to parse bridge abi")
+        .handle_error()?;
+    let details = bridge::get_details(code, data, &bridge_abi).handle_error()?;
+    convert_bridge_details(details, format).handle_error()
+}
+
+#[wasm_bindgen(js_name = "getTonEventConfigurationDetails")]
+pub fn get_ton_event_configuration_details(
+    account_state: &BocInput,
+    configuration_abi: &str,
+    format: Option<AddressFormatInput>,
+) -> Result<TonEventConfigurationDetails, JsValue> {
+    utils::set_panic_hook();
+    let format = parse_address_format(format).handle_error()?;
+    let account_state = parse_boc_input(account_state)
+        .map_err(|_| "Failed to decode account state")
+        .handle_error()?;
+    let (code, data) = utils::decode_account_state(&account_state).handle_error()?;
+    let configuration_abi = ton_abi::Contract::load(std::io::Cursor::new(configuration_abi))
+        .map_err(|_| "Failed to parse configuration abi")
+        .handle_error()?;
+    let details = configuration::get_ton_event_configuration_details(code, data, &configuration_abi).handle_error()?;
+
+    Ok(TonEventConfigurationDetails {
+        basic_init_data: convert_basic_configuration_init_data(details.basic_init_data, format).handle_error()?,
+        event_address: address::format_address(&details.init_data.event_address, format).handle_error()?,
+        proxy_address: utils::format_eth_address(&details.init_data.proxy_address),
+        start_timestamp: details.init_data.start_timestamp,
+    })
+}
+
+#[wasm_bindgen(js_name = "getEthereumEventConfigurationDetails")]
+pub fn get_ethereum_event_configuration_details(
+    account_state: &BocInput,
+    configuration_abi: &str,
+    format: Option<AddressFormatInput>,
+) -> Result<EthereumEventConfigurationDetails, JsValue> {
+    utils::set_panic_hook();
+    let format = parse_address_format(format).handle_error()?;
+    let account_state = parse_boc_input(account_state)
+        .map_err(|_| "Failed to decode account state")
+        .handle_error()?;
+    let (code, data) = utils::decode_account_state(&account_state).handle_error()?;
+    let configuration_abi = ton_abi::Contract::load(std::io::Cursor::new(configuration_abi))
+        .map_err(|_| "Failed to parse configuration abi")
+        .handle_error()?;
+    let details =
+        configuration::get_ethereum_event_configuration_details(code, data, &configuration_abi).handle_error()?;
+
+    Ok(EthereumEventConfigurationDetails {
+        basic_init_data: convert_basic_configuration_init_data(details.basic_init_data, format).handle_error()?,
+        event_address: utils::format_eth_address(&details.init_data.event_address),
+        event_blocks_to_confirm: details.init_data.event_blocks_to_confirm,
+        proxy_address: address::format_address(&details.init_data.proxy_address, format).handle_error()?,
+        start_block_number: details.init_data.start_block_number,
+    })
+}
+
+#[wasm_bindgen(js_name = "getCheckedDetails")]
+pub fn get_checked_details(
+    account_state: &BocInput,
+    allowlist: &CodeHashAllowlist,
+    network: &str,
+    direction: EventDirection,
+    format: Option<AddressFormatInput>,
+) -> Result<TonEventDetails, JsValue> {
+    utils::set_panic_hook();
+    let format = parse_address_format(format).handle_error()?;
+    let account_state = parse_boc_input(account_state)
+        .map_err(|_| "Failed to decode account state")
+        .handle_error()?;
+    let (code, data) = utils::decode_account_state(&account_state).handle_error()?;
+    let direction = direction.parse().handle_error()?;
+    let checked = authenticity::get_checked_details(code, data, &allowlist.inner, network, direction).handle_error()?;
+
+    let mut details = convert_event_details(checked.details, format).handle_error()?;
+    details.authenticity = Some(checked.authenticity.into());
+    Ok(details)
+}
+
+#[wasm_bindgen(js_name = "getShardStateDetails")]
+pub fn get_shard_state_details(
+    shard_state: &BocInput,
+    code_hashes: StringArray,
+    callback: &ShardStateCallback,
+    format: Option<AddressFormatInput>,
+) -> Result<(), JsValue> {
+    utils::set_panic_hook();
+    let format = parse_address_format(format).handle_error()?;
+    let shard_state = parse_boc_input(shard_state)
+        .map_err(|_| "Failed to decode shard state")
+        .handle_error()?;
+    let callback = callback.unchecked_ref::<js_sys::Function>();
+    let code_hashes = code_hashes
+        .unchecked_into::<js_sys::Array>()
+        .iter()
+        .map(|hash| {
+            hash.as_string()
+                .ok_or("Invalid hash")
+                .and_then(|hash| utils::parse_hash(&hash))
+        })
+        .collect::<Result<Vec<_>>>()
+        .handle_error()?;
+
+    let mut callback_error = None;
+    shard::iterate_event_accounts(&shard_state, &code_hashes, |address, details| {
+        let (details, error) = match details.and_then(|details| convert_event_details(details, format)) {
+            Ok(details) => (JsValue::from(details), JsValue::NULL),
+            Err(e) => (JsValue::NULL, make_error(e)),
+        };
+
+        let address = JsValue::from(address::format_any_address(&address, format));
+        match callback.call3(&JsValue::NULL, &address, &details, &error) {
+            Ok(result) => result.as_bool() != Some(false),
+            Err(e) => {
+                callback_error = Some(e);
+                false
+            }
+        }
+    })
+    .handle_error()?;
+
+    match callback_error {
+        Some(e) => Err(e),
+        None => Ok(()),
+    }
+}
+
+#[wasm_bindgen(js_name = "inspectBoc")]
+pub fn inspect_boc(boc: &BocInput, max_depth: Option<u32>) -> Result<String, JsValue> {
+    utils::set_panic_hook();
+    let boc = parse_boc_input(boc)
+        .map_err(|_| "Failed to parse Cell")
+        .handle_error()?;
+    let tree = inspect::inspect_boc(&boc, max_depth.map(|depth| depth as usize)).handle_error()?;
+    Ok(tree.to_string())
+}
+
+#[wasm_bindgen]
+pub fn disassemble(code: &BocInput, abis: StringArray) -> Result<String, JsValue> {
+    utils::set_panic_hook();
+    let code = parse_boc_input(code)
+        .and_then(|code| utils::deserialize_cell(&code))
+        .handle_error()?;
+    let abis = parse_contract_abis(abis).handle_error()?;
+
+    disasm::disassemble(&code, &disasm::known_functions(&abis)).handle_error()
+}
+
+#[wasm_bindgen(js_name = "listCodeFunctions")]
+pub fn list_code_functions(code: &BocInput, abis: StringArray) -> Result<CodeFunctions, JsValue> {
+    utils::set_panic_hook();
+    let code = parse_boc_input(code)
+        .and_then(|code| utils::deserialize_cell(&code))
+        .handle_error()?;
+    let abis = parse_contract_abis(abis).handle_error()?;
+    let functions = functions::list_code_functions(&code, &abis).handle_error()?;
+    Ok(functions.into())
+}
+
+#[wasm_bindgen(js_name = "decodeTransaction")]
+pub fn decode_transaction(
+    transaction: &BocInput,
+    abis: StringArray,
+    format: Option<AddressFormatInput>,
+) -> Result<String, JsValue> {
+    utils::set_panic_hook();
+    let format = parse_address_format(format).handle_error()?;
+    let transaction = parse_transaction(transaction).handle_error()?;
+    let abis = parse_contract_abis(abis).handle_error()?;
+    let decoded = decode::decode_transaction(&transaction, &abis).handle_error()?;
+    Ok(decoded.to_json(format).handle_error()?.to_string())
+}
+
+/// Same as `decodeTransaction` but returns an object with lt and integers as `BigInt`
+#[wasm_bindgen(js_name = "decodeTransactionObject")]
+pub fn decode_transaction_object(
+    transaction: &BocInput,
+    abis: StringArray,
+    format: Option<AddressFormatInput>,
+) -> Result<DecodedTransactionObject, JsValue> {
+    utils::set_panic_hook();
+    let format = parse_address_format(format).handle_error()?;
+    let transaction = parse_transaction(transaction).handle_error()?;
+    let abis = parse_contract_abis(abis).handle_error()?;
+    let decoded = decode::decode_transaction(&transaction, &abis).handle_error()?;
+    decoded
+        .to_value::<JsValue>(format)
+        .map(JsCast::unchecked_into)
+        .handle_error()
+}
+
+#[wasm_bindgen(js_name = "decodeMessage")]
+pub fn decode_message(
+    message: &BocInput,
+    abis: StringArray,
+    format: Option<AddressFormatInput>,
+) -> Result<String, JsValue> {
+    utils::set_panic_hook();
+    let format = parse_address_format(format).handle_error()?;
+    let message = parse_message(message).handle_error()?;
+    let abis = parse_contract_abis(abis).handle_error()?;
+    let decoded = decode::decode_message(&message, &abis).handle_error()?;
+    Ok(decoded.to_json(format).handle_error()?.to_string())
+}
+
+/// Same as `decodeMessage` but returns an object with integers as `BigInt`
+#[wasm_bindgen(js_name = "decodeMessageObject")]
+pub fn decode_message_object(
+    message: &BocInput,
+    abis: StringArray,
+    format: Option<AddressFormatInput>,
+) -> Result<DecodedMessageObject, JsValue> {
+    utils::set_panic_hook();
+    let format = parse_address_format(format).handle_error()?;
+    let message = parse_message(message).handle_error()?;
+    let abis = parse_contract_abis(abis).handle_error()?;
+    let decoded = decode::decode_message(&message, &abis).handle_error()?;
+    decoded
+        .to_value::<JsValue>(format)
+        .map(JsCast::unchecked_into)
+        .handle_error()
+}
+
+fn parse_transaction(transaction: &BocInput) -> Result<ton_block::Transaction> {
+    let transaction = parse_boc_input(transaction).map_err(|_| "Failed to decode transaction")?;
+    ton_block::Transaction::construct_from_bytes(&transaction).map_err(|_| "Failed to decode transaction")
+}
+
+fn parse_message(message: &BocInput) -> Result<ton_block::Message> {
+    let message = parse_boc_input(message).map_err(|_| "Failed to decode message")?;
+    ton_block::Message::construct_from_bytes(&message).map_err(|_| "Failed to decode message")
+}
+
+#[wasm_bindgen(js_name = "getEventSummary")]
+pub fn get_event_summary(
+    event: &TonEventDetails,
+    event_abi: &str,
+    decimals: Option<u8>,
+    format: Option<AddressFormatInput>,
+) -> Result<Option<EventSummary>, JsValue> {
+    utils::set_panic_hook();
+    let format = parse_address_format(format).handle_error()?;
+    let event_data = utils::decode_cell(&event.init_data.event_data).handle_error()?;
+    summary::summarize_event(event_data, event_abi)
+        .handle_error()?
+        .map(|summary| convert_event_summary(summary, decimals, None, format))
+        .transpose()
+        .handle_error()
+}
+
+#[wasm_bindgen(js_name = "formatAddress")]
+pub fn format_address(address: &str, format: &AddressFormat) -> Result<String, JsValue> {
+    let address = address::parse_address(address, None).handle_error()?;
+    address::format_address(&address, format.inner).handle_error()
+}
+
+/// Converts address into the raw form. User friendly addresses must use the url safe base64
+/// alphabet if `urlSafe` is `true` and the standard one if it is `false`, both are accepted if omitted
+#[wasm_bindgen(js_name = "parseAddress")]
+pub fn parse_address(address: &str, url_safe: Option<bool>) -> Result<String, JsValue> {
+    let address = address::parse_address(address, url_safe).handle_error()?;
+    Ok(address.to_string())
+}
+
+#[wasm_bindgen(js_name = "formatEthAddress")]
+pub fn format_eth_address(address: &str, strict: Option<bool>) -> Result<String, JsValue> {
+    utils::set_panic_hook();
+    let address = utils::parse_eth_address_with(address, strict.unwrap_or_default()).handle_error()?;
+    Ok(utils::format_eth_address(&address))
 }
 
 #[wasm_bindgen(js_name = "encodeEthAddress")]
-pub fn encode_eth_address(address: &str) -> Result<String, JsValue> {
+pub fn encode_eth_address(address: &str, strict: Option<bool>) -> Result<String, JsValue> {
+    utils::set_panic_hook();
+    let data = eth_address_cell(address, strict).handle_error()?;
+    utils::encode_cell(&data).handle_error()
+}
+
+#[wasm_bindgen(js_name = "encodeEthAddressBytes")]
+pub fn encode_eth_address_bytes(address: &str, strict: Option<bool>) -> Result<Vec<u8>, JsValue> {
     utils::set_panic_hook();
-    let address = ethabi::Address::from_str(address).map_err(|_| "Failed to decode proxy address")?;
-    let data: ton_types::Cell = ton_abi::TokenValue::pack_values_into_chain(
+    let data = eth_address_cell(address, strict).handle_error()?;
+    utils::serialize_cell(&data).handle_error()
+}
+
+fn eth_address_cell(address: &str, strict: Option<bool>) -> Result<ton_types::Cell> {
+    let address = utils::parse_eth_address_with(address, strict.unwrap_or_default())?;
+    ton_abi::TokenValue::pack_values_into_chain(
         &[ton_abi::Token {
             name: String::default(),
             value: ton_abi::TokenValue::Uint(ton_abi::Uint {
@@ -39,45 +513,297 @@ pub fn encode_eth_address(address: &str) -> Result<String, JsValue> {
         Vec::new(),
         2,
     )
-    .handle_error()?
-    .into_cell()
-    .handle_error()?;
-    let data = ton_types::serialize_toc(&data).handle_error()?;
-    Ok(base64::encode(&data))
+    .and_then(|builder| builder.into_cell())
+    .map_err(|_| "Failed to encode ETH address")
 }
 
 #[wasm_bindgen(js_name = "encodePayload")]
-pub fn encode_payload(event: &TonEventDetails, eth_abi: &str, proxy_address: &str) -> Result<String, JsValue> {
+pub fn encode_payload(
+    event: &TonEventDetails,
+    eth_abi: &str,
+    proxy_address: &str,
+    strict: Option<bool>,
+) -> Result<String, JsValue> {
     utils::set_panic_hook();
-    let proxy_address = ethabi::Address::from_str(proxy_address).map_err(|_| "Failed to decode proxy address")?;
+    let proxy_address = utils::parse_eth_address_with(proxy_address, strict.unwrap_or_default()).handle_error()?;
     let payload = convert_eth_payload(event, proxy_address).handle_error()?;
     eth::encode_eth_payload(payload, eth_abi)
         .map(|payload| hex::encode(&payload))
         .handle_error()
 }
 
-fn convert_eth_payload(value: &TonEventDetails, proxy_address: ethabi::Address) -> Result<eth::EthPayload> {
-    let event_transaction =
-        hex::decode(&value.init_data.event_transaction).map_err(|_| "Failed to parse event transaction")?;
-    let event_transaction_lt =
-        u64::from_str(&value.init_data.event_transaction_lt).map_err(|_| "Failed to parse event transaction lt")?;
+#[wasm_bindgen(js_name = "encodeEventDataFromLog")]
+pub fn encode_event_data_from_log(
+    topics: StringArray,
+    data: &str,
+    eth_event_abi: &str,
+    ton_event_abi: &str,
+) -> Result<String, JsValue> {
+    utils::set_panic_hook();
+    let event_data = event_data_from_log(topics, data, eth_event_abi, ton_event_abi).handle_error()?;
+    utils::encode_cell(&event_data).handle_error()
+}
 
-    let event_data = base64::decode(&value.init_data.event_data).map_err(|_| "Failed to parse Cell")?;
-    let event_data = ton_types::deserialize_tree_of_cells(&mut std::io::Cursor::new(event_data))
-        .map_err(|_| "Failed to parse Cell")?;
+#[wasm_bindgen(js_name = "encodeEventDataFromLogBytes")]
+pub fn encode_event_data_from_log_bytes(
+    topics: StringArray,
+    data: &str,
+    eth_event_abi: &str,
+    ton_event_abi: &str,
+) -> Result<Vec<u8>, JsValue> {
+    utils::set_panic_hook();
+    let event_data = event_data_from_log(topics, data, eth_event_abi, ton_event_abi).handle_error()?;
+    utils::serialize_cell(&event_data).handle_error()
+}
 
-    let event_configuration = MsgAddressInt::from_str(&value.init_data.ton_event_configuration)
-        .map_err(|_| "Failed to parse TON event configuration address")?;
+fn event_data_from_log(
+    topics: StringArray,
+    data: &str,
+    eth_event_abi: &str,
+    ton_event_abi: &str,
+) -> Result<ton_types::Cell> {
+    let topics = topics
+        .unchecked_into::<js_sys::Array>()
+        .iter()
+        .map(|topic| {
+            let topic = topic.as_string().ok_or("Invalid log topic")?;
+            match hex::decode(topic.strip_prefix("0x").unwrap_or(&topic)) {
+                Ok(topic) if topic.len() == 32 => Ok(ethabi::Hash::from_slice(&topic)),
+                _ => Err("Invalid log topic"),
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let data = hex::decode(data.strip_prefix("0x").unwrap_or(data)).map_err(|_| "Invalid log data")?;
+
+    eth::encode_event_data_from_log(topics, data, eth_event_abi, ton_event_abi)
+}
+
+#[wasm_bindgen(js_name = "verifyEventTransaction")]
+pub fn verify_event_transaction(
+    event: &TonEventDetails,
+    transaction: &BocInput,
+    event_abi: &str,
+) -> Result<(), JsValue> {
+    utils::set_panic_hook();
+    let init_data = parse_init_data(&event.init_data).handle_error()?;
+    let transaction = parse_boc_input(transaction)
+        .map_err(|_| "Failed to decode transaction")
+        .handle_error()?;
+    let transaction = ton_types::deserialize_tree_of_cells(&mut std::io::Cursor::new(transaction))
+        .map_err(|_| "Failed to decode transaction")
+        .handle_error()?;
+    verify::verify_event_transaction(&init_data, transaction, event_abi).handle_error()
+}
+
+#[wasm_bindgen(js_name = "getVoteTimeline")]
+pub fn get_vote_timeline(
+    event: &TonEventDetails,
+    event_address: &str,
+    transactions: BocInputArray,
+    contract_abi: &str,
+    functions: Option<VoteFunctionsInput>,
+    format: Option<AddressFormatInput>,
+) -> Result<VoteArray, JsValue> {
+    utils::set_panic_hook();
+    let format = parse_address_format(format).handle_error()?;
+    let event_address = address::parse_address(event_address, None).handle_error()?;
+    let functions = parse_vote_functions(functions).handle_error()?;
+    let contract_abi = ton_abi::Contract::load(std::io::Cursor::new(contract_abi))
+        .map_err(|_| "Failed to parse contract abi")
+        .handle_error()?;
+    let transactions = transactions
+        .unchecked_into::<js_sys::Array>()
+        .iter()
+        .map(|transaction| parse_transaction(transaction.unchecked_ref()))
+        .collect::<Result<Vec<_>>>()
+        .handle_error()?;
+
+    let votes = history::build_vote_timeline(
+        &transactions,
+        &event_address,
+        &contract_abi,
+        &functions,
+        event.init_data.required_confirmations,
+        event.init_data.required_rejections,
+    )
+    .handle_error()?;
+
+    Ok(votes
+        .into_iter()
+        .map(|vote| JsValue::from(convert_vote(vote, format)))
+        .collect::<js_sys::Array>()
+        .unchecked_into())
+}
+
+#[wasm_bindgen(js_name = "analyzeQuorum")]
+pub fn analyze_quorum(
+    event: &TonEventDetails,
+    relays: &RelaySet,
+    eth_abi: &str,
+    proxy_address: &str,
+    strict: Option<bool>,
+    format: Option<AddressFormatInput>,
+) -> Result<QuorumAnalysis, JsValue> {
+    utils::set_panic_hook();
+    let format = parse_address_format(format).handle_error()?;
+    let proxy_address = utils::parse_eth_address_with(proxy_address, strict.unwrap_or_default()).handle_error()?;
+    let payload = convert_eth_payload(event, proxy_address).handle_error()?;
+    let payload = eth::encode_eth_payload(payload, eth_abi).handle_error()?;
+
+    let details = parse_event_details(event).handle_error()?;
+    let analysis = quorum::analyze_quorum(&details, &relays.relays, &payload);
+
+    let to_strings = |addresses: Vec<MsgAddressInt>| {
+        addresses
+            .iter()
+            .map(|address| address::format_any_address(address, format))
+            .collect::<Vec<_>>()
+    };
+    Ok(QuorumAnalysis {
+        confirmations: analysis.confirmations as u32,
+        rejections: analysis.rejections as u32,
+        required_confirmations: analysis.required_confirmations,
+        required_rejections: analysis.required_rejections,
+        not_voted: to_strings(analysis.not_voted),
+        invalid_signatures: to_strings(analysis.invalid_signatures),
+        duplicate_votes: to_strings(analysis.duplicate_votes),
+        unknown_relays: to_strings(analysis.unknown_relays),
+        status_consistent: analysis.status_consistent,
+    })
+}
+
+#[wasm_bindgen(js_name = "computeEventAddress")]
+pub fn compute_event_address(
+    event: &TonEventDetails,
+    event_code: &BocInput,
+    workchain_id: i32,
+    format: Option<AddressFormatInput>,
+) -> Result<String, JsValue> {
+    utils::set_panic_hook();
+    let format = parse_address_format(format).handle_error()?;
+    let init_data = parse_init_data(&event.init_data).handle_error()?;
+    let event_code = parse_boc_input(event_code)
+        .and_then(|code| utils::deserialize_cell(&code))
+        .handle_error()?;
+    contract::compute_event_address(event_code, &init_data, workchain_id)
+        .and_then(|address| address::format_address(&address, format))
+        .handle_error()
+}
+
+#[wasm_bindgen(js_name = "verifyEventAddress")]
+pub fn verify_event_address(account_state: &BocInput, event_code: &BocInput) -> Result<bool, JsValue> {
+    utils::set_panic_hook();
+    let account_state = parse_boc_input(account_state)
+        .map_err(|_| "Failed to decode account state")
+        .handle_error()?;
+    let account = ton_block::Account::construct_from_bytes(&account_state)
+        .map_err(|_| "Failed to decode account state")
+        .handle_error()?;
+    let event_code = parse_boc_input(event_code)
+        .and_then(|code| utils::deserialize_cell(&code))
+        .handle_error()?;
+    contract::verify_event_address(&account, event_code).handle_error()
+}
+
+fn parse_boc_input(boc: &BocInput) -> Result<Vec<u8>> {
+    if let Some(boc) = boc.as_string() {
+        utils::decode_boc(&boc)
+    } else if let Some(boc) = boc.dyn_ref::<js_sys::Uint8Array>() {
+        Ok(boc.to_vec())
+    } else {
+        Err("Invalid BOC")
+    }
+}
+
+// BOCs are stored as base64 regardless of the input encoding
+fn boc_input_to_base64(boc: &BocInput) -> Result<String> {
+    parse_boc_input(boc).map(|boc| base64::encode(&boc))
+}
+
+fn normalize_boc(boc: &str) -> Result<String> {
+    utils::decode_boc(boc).map(|boc| base64::encode(&boc))
+}
+
+fn make_big_int(value: &dyn ToString) -> Result<js_sys::BigInt> {
+    js_sys::BigInt::new(&JsValue::from(value.to_string())).map_err(|_| "Failed to convert integer")
+}
+
+/// Accepts decimal string, `BigInt` or safe integer number
+fn parse_u64(value: &JsValue) -> Result<u64> {
+    if let Some(value) = value.as_string() {
+        value.parse::<u64>().map_err(|_| "Invalid u64 value")
+    } else if let Some(value) = value.dyn_ref::<js_sys::BigInt>() {
+        let value = value.to_string(10).map_err(|_| "Invalid u64 value")?;
+        String::from(value).parse::<u64>().map_err(|_| "Invalid u64 value")
+    } else if let Some(value) = value.as_f64() {
+        match value {
+            value if value >= 0.0 && value <= MAX_SAFE_INTEGER && value.fract() == 0.0 => Ok(value as u64),
+            _ => Err("Invalid u64 value"),
+        }
+    } else {
+        Err("Invalid u64 value")
+    }
+}
+
+const MAX_SAFE_INTEGER: f64 = 9007199254740991.0;
+
+/// Integers are converted to `BigInt`
+impl decode::DecodedValue for JsValue {
+    fn null() -> Self {
+        JsValue::NULL
+    }
+
+    fn string(value: &str) -> Self {
+        JsValue::from(value)
+    }
+
+    fn number(value: u32) -> Self {
+        JsValue::from(value)
+    }
+
+    fn integer(value: &dyn ToString) -> Result<Self> {
+        make_big_int(value).map(JsValue::from)
+    }
+
+    fn array(values: Vec<Self>) -> Result<Self> {
+        Ok(values.into_iter().collect::<js_sys::Array>().into())
+    }
+
+    fn object(fields: Vec<(&str, Self)>) -> Result<Self> {
+        let object = js_sys::Object::new();
+        for (name, value) in fields {
+            js_sys::Reflect::set(&object, &JsValue::from(name), &value).map_err(|_| "Failed to build object")?;
+        }
+        Ok(object.into())
+    }
+
+    fn json(value: serde_json::Value) -> Result<Self> {
+        js_sys::JSON::parse(&value.to_string()).map_err(|_| "Failed to convert tokens")
+    }
+}
+
+fn parse_contract_abis(abis: StringArray) -> Result<Vec<ton_abi::Contract>> {
+    abis.unchecked_into::<js_sys::Array>()
+        .iter()
+        .map(|abi| {
+            let abi = abi.as_string().ok_or("Failed to parse contract abi")?;
+            ton_abi::Contract::load(std::io::Cursor::new(abi)).map_err(|_| "Failed to parse contract abi")
+        })
+        .collect()
+}
+
+fn convert_eth_payload(value: &TonEventDetails, proxy_address: ethabi::Address) -> Result<eth::EthPayload> {
+    let init_data = parse_init_data(&value.init_data)?;
 
     Ok(eth::EthPayload {
-        event_transaction: event_transaction.into(),
-        event_transaction_lt,
-        event_timestamp: value.init_data.event_timestamp,
-        event_index: value.init_data.event_index,
-        event_data,
-        event_configuration,
-        required_confirmations: value.init_data.required_confirmations,
-        required_rejections: value.init_data.required_rejections,
+        event_transaction: init_data.event_transaction,
+        event_transaction_lt: init_data.event_transaction_lt,
+        event_timestamp: init_data.event_timestamp,
+        event_index: init_data.event_index,
+        event_data: init_data.event_data,
+        event_configuration: init_data.ton_event_configuration,
+        required_confirmations: init_data.required_confirmations,
+        required_rejections: init_data.required_rejections,
         proxy: proxy_address,
     })
 }
@@ -89,10 +815,76 @@ pub struct TonEventDetails {
     confirmations: Vec<String>,
     rejections: Vec<String>,
     signatures: Vec<String>,
+    authenticity: Option<Authenticity>,
 }
 
 #[wasm_bindgen]
 impl TonEventDetails {
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        init_data: &TonEventInitData,
+        status: EventStatus,
+        confirmations: TonAddressArray,
+        rejections: TonAddressArray,
+        signatures: StringArray,
+    ) -> Result<TonEventDetails, JsValue> {
+        utils::set_panic_hook();
+        let to_strings = |values: JsValue, error: &'static str| {
+            values
+                .unchecked_into::<js_sys::Array>()
+                .iter()
+                .map(|value| value.as_string().ok_or(error))
+                .collect::<Result<Vec<_>>>()
+        };
+
+        let details = TonEventDetails {
+            init_data: init_data.clone(),
+            status,
+            confirmations: to_strings(confirmations.into(), "Failed to parse relay address").handle_error()?,
+            rejections: to_strings(rejections.into(), "Failed to parse relay address").handle_error()?,
+            signatures: to_strings(signatures.into(), "Failed to parse signature")
+                .and_then(normalize_signatures)
+                .handle_error()?,
+            authenticity: None,
+        };
+        parse_event_details(&details).handle_error()?;
+        Ok(details)
+    }
+
+    #[wasm_bindgen(js_name = "fromJSON")]
+    pub fn from_json(json: &str) -> Result<TonEventDetails, JsValue> {
+        utils::set_panic_hook();
+        let json = serde_json::from_str::<TonEventDetailsJson>(json)
+            .map_err(|_| "Failed to parse event details")
+            .handle_error()?;
+
+        let details = TonEventDetails {
+            init_data: json.init_data.try_into_init_data().handle_error()?,
+            status: EventStatus::from_json_str(&json.status).handle_error()?,
+            confirmations: json.confirmations,
+            rejections: json.rejections,
+            signatures: normalize_signatures(json.signatures).handle_error()?,
+            authenticity: None,
+        };
+        parse_event_details(&details).handle_error()?;
+        Ok(details)
+    }
+
+    #[wasm_bindgen(js_name = "toJSON")]
+    pub fn to_json(&self) -> Result<TonEventDetailsJsonObject, JsValue> {
+        let json = serde_json::json!({
+            "initData": self.init_data.to_json_value(),
+            "status": self.status.json_str(),
+            "confirmations": self.confirmations,
+            "rejections": self.rejections,
+            "signatures": self.signatures,
+        });
+        js_sys::JSON::parse(&json.to_string())
+            .map(JsCast::unchecked_into)
+            .map_err(|_| "Failed to convert event details")
+            .handle_error()
+    }
+
     #[wasm_bindgen(getter = initData)]
     pub fn init_data(&self) -> TonEventInitData {
         self.init_data.clone()
@@ -104,125 +896,464 @@ impl TonEventDetails {
     }
 
     #[wasm_bindgen(getter)]
-    pub fn confirmations(&self) -> js_sys::Array {
-        self.confirmations.iter().map(JsValue::from).collect()
+    pub fn confirmations(&self) -> TonAddressArray {
+        self.confirmations
+            .iter()
+            .map(JsValue::from)
+            .collect::<js_sys::Array>()
+            .unchecked_into()
     }
 
     #[wasm_bindgen(getter)]
-    pub fn rejections(&self) -> js_sys::Array {
-        self.rejections.iter().map(JsValue::from).collect()
+    pub fn rejections(&self) -> TonAddressArray {
+        self.rejections
+            .iter()
+            .map(JsValue::from)
+            .collect::<js_sys::Array>()
+            .unchecked_into()
     }
 
     #[wasm_bindgen(getter)]
-    pub fn signatures(&self) -> js_sys::Array {
-        self.signatures.iter().map(JsValue::from).collect()
+    pub fn signatures(&self) -> SignatureArray {
+        self.signatures
+            .iter()
+            .map(|signature| JsValue::from(Signature::new(signature)))
+            .collect::<js_sys::Array>()
+            .unchecked_into()
     }
-}
 
-fn convert_event_details(data: contract::TonEventDetails) -> Result<TonEventDetails> {
-    Ok(TonEventDetails {
-        init_data: convert_init_data(data.init_data)?,
-        status: data.status.into(),
-        confirmations: data.confirms.into_iter().map(|item| item.to_string()).collect(),
-        rejections: data.rejections.into_iter().map(|item| item.to_string()).collect(),
-        signatures: data.signatures.into_iter().map(|item| hex::encode(&item)).collect(),
-    })
+    /// Set only by `getCheckedDetails`, `undefined` for details from other functions
+    #[wasm_bindgen(getter)]
+    pub fn authenticity(&self) -> Option<Authenticity> {
+        self.authenticity
+    }
+
+    #[wasm_bindgen(js_name = "withAddressFormat")]
+    pub fn with_address_format(&self, format: &AddressFormat) -> Result<TonEventDetails, JsValue> {
+        let details = parse_event_details(self).handle_error()?;
+        let mut details = convert_event_details(details, format.inner).handle_error()?;
+        details.authenticity = self.authenticity;
+        Ok(details)
+    }
 }
 
+/// Relay signature, malformed signatures are kept with the parsing error.
+/// All hex values are returned without `0x` prefix
 #[wasm_bindgen]
 #[derive(Clone)]
-pub struct TonEventInitData {
-    event_transaction: String,
-    event_transaction_lt: String,
-    event_timestamp: u32,
-    event_index: u32,
-    event_data: String,
-    ton_event_configuration: String,
-    required_confirmations: u16,
-    required_rejections: u16,
-    configuration_meta: String,
+pub struct Signature {
+    raw: String,
+    parsed: Result<signature::Signature>,
 }
 
 #[wasm_bindgen]
-impl TonEventInitData {
-    #[wasm_bindgen(getter = eventTransaction)]
-    pub fn event_transaction(&self) -> String {
-        self.event_transaction.clone()
+impl Signature {
+    #[wasm_bindgen(constructor)]
+    pub fn new(signature: &str) -> Signature {
+        let parsed = decode_signature(signature).and_then(|raw| signature::Signature::parse(&raw));
+        Self {
+            raw: signature.to_owned(),
+            parsed,
+        }
     }
 
-    #[wasm_bindgen(getter = eventTransactionLt)]
-    pub fn event_transaction_lt(&self) -> String {
-        self.event_transaction_lt.clone()
+    /// Original signature as stored by the relay
+    #[wasm_bindgen(getter)]
+    pub fn raw(&self) -> String {
+        self.raw.clone()
     }
 
-    #[wasm_bindgen(getter = eventTimestamp)]
-    pub fn event_timestamp(&self) -> u32 {
-        self.event_timestamp
+    #[wasm_bindgen(getter)]
+    pub fn malformed(&self) -> bool {
+        self.parsed.is_err()
     }
 
-    #[wasm_bindgen(getter = eventIndex)]
-    pub fn event_index(&self) -> u32 {
-        self.event_index
+    #[wasm_bindgen(getter)]
+    pub fn error(&self) -> Option<String> {
+        self.parsed.as_ref().err().map(|e| e.to_string())
     }
 
-    #[wasm_bindgen(getter = eventData)]
-    pub fn event_data(&self) -> String {
-        self.event_data.clone()
+    #[wasm_bindgen(getter)]
+    pub fn r(&self) -> Option<String> {
+        self.parsed.as_ref().ok().map(|signature| hex::encode(&signature.r))
     }
 
-    #[wasm_bindgen(getter = tonEventConfiguration)]
-    pub fn ton_event_configuration(&self) -> String {
-        self.ton_event_configuration.clone()
+    /// Always in the lower half of the curve order
+    #[wasm_bindgen(getter)]
+    pub fn s(&self) -> Option<String> {
+        self.parsed.as_ref().ok().map(|signature| hex::encode(&signature.s))
     }
 
-    #[wasm_bindgen(getter = requiredConfirmations)]
-    pub fn required_confirmations(&self) -> u16 {
-        self.required_confirmations
+    /// Either 27 or 28
+    #[wasm_bindgen(getter)]
+    pub fn v(&self) -> Option<u8> {
+        self.parsed.as_ref().ok().map(|signature| signature.v)
     }
 
-    #[wasm_bindgen(getter = requiredRejections)]
-    pub fn required_rejections(&self) -> u16 {
-        self.required_rejections
+    /// 65 bytes `r || s || v`
+    #[wasm_bindgen(getter)]
+    pub fn bytes(&self) -> Option<String> {
+        self.parsed
+            .as_ref()
+            .ok()
+            .map(|signature| hex::encode(signature.to_bytes()))
     }
 
-    #[wasm_bindgen(getter = configurationMeta)]
-    pub fn configuration_meta(&self) -> String {
-        self.configuration_meta.clone()
+    /// 64 bytes EIP-2098 compact form
+    #[wasm_bindgen(getter)]
+    pub fn compact(&self) -> Option<String> {
+        self.parsed
+            .as_ref()
+            .ok()
+            .map(|signature| hex::encode(signature.to_compact()))
     }
-}
 
-fn convert_init_data(data: contract::TonEventInitData) -> Result<TonEventInitData> {
-    let event_data = match ton_types::serialize_toc(&data.event_data) {
-        Ok(data) => base64::encode(&data),
-        Err(_) => return Err("Failed to serialize Cell"),
-    };
+    #[wasm_bindgen(getter = highS)]
+    pub fn high_s(&self) -> bool {
+        matches!(&self.parsed, Ok(signature) if signature.high_s)
+    }
 
-    let configuration_meta = match ton_types::serialize_toc(&data.configuration_meta) {
-        Ok(data) => base64::encode(&data),
-        Err(_) => return Err("Failed to serialize Cell"),
-    };
+    /// Whether `s` or `v` were changed to get the canonical form
+    #[wasm_bindgen(getter)]
+    pub fn normalized(&self) -> bool {
+        matches!(&self.parsed, Ok(signature) if signature.normalized)
+    }
+}
+
+fn decode_signature(signature: &str) -> Result<Vec<u8>> {
+    hex::decode(signature.strip_prefix("0x").unwrap_or(signature)).map_err(|_| "Invalid signature hex")
+}
+
+/// Signatures are stored as plain lowercase hex
+fn normalize_signatures(signatures: Vec<String>) -> Result<Vec<String>> {
+    signatures
+        .iter()
+        .map(|signature| decode_signature(signature).map(hex::encode))
+        .collect()
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TonEventDetailsJson {
+    init_data: TonEventInitDataJson,
+    status: String,
+    #[serde(default)]
+    confirmations: Vec<String>,
+    #[serde(default)]
+    rejections: Vec<String>,
+    #[serde(default)]
+    signatures: Vec<String>,
+}
+
+fn parse_event_details(value: &TonEventDetails) -> Result<contract::TonEventDetails> {
+    let parse_addresses = |addresses: &[String]| {
+        addresses
+            .iter()
+            .map(|address| address::parse_address(address, None).map_err(|_| "Failed to parse relay address"))
+            .collect::<Result<Vec<_>>>()
+    };
+
+    Ok(contract::TonEventDetails {
+        init_data: parse_init_data(&value.init_data)?,
+        status: value.status.parse()?,
+        confirms: parse_addresses(&value.confirmations)?,
+        rejections: parse_addresses(&value.rejections)?,
+        signatures: value
+            .signatures
+            .iter()
+            .map(|signature| decode_signature(signature))
+            .collect::<Result<_>>()?,
+    })
+}
+
+fn convert_event_details(data: contract::TonEventDetails, format: address::AddressFormat) -> Result<TonEventDetails> {
+    let format_addresses = |addresses: Vec<MsgAddressInt>| {
+        addresses
+            .iter()
+            .map(|address| address::format_any_address(address, format))
+            .collect::<Vec<_>>()
+    };
+
+    Ok(TonEventDetails {
+        init_data: convert_init_data(data.init_data, format)?,
+        status: data.status.into(),
+        confirmations: format_addresses(data.confirms),
+        rejections: format_addresses(data.rejections),
+        signatures: data.signatures.into_iter().map(|item| hex::encode(&item)).collect(),
+        authenticity: None,
+    })
+}
+
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct TonEventInitData {
+    event_transaction: String,
+    event_transaction_lt: u64,
+    event_timestamp: u32,
+    event_index: u32,
+    event_data: String,
+    ton_event_configuration: String,
+    required_confirmations: u16,
+    required_rejections: u16,
+    configuration_meta: String,
+}
+
+#[wasm_bindgen]
+impl TonEventInitData {
+    #[allow(clippy::too_many_arguments)]
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        event_transaction: String,
+        event_transaction_lt: JsValue,
+        event_timestamp: u32,
+        event_index: u32,
+        event_data: &BocInput,
+        ton_event_configuration: String,
+        required_confirmations: u16,
+        required_rejections: u16,
+        configuration_meta: &BocInput,
+    ) -> Result<TonEventInitData, JsValue> {
+        utils::set_panic_hook();
+        let init_data = TonEventInitData {
+            event_transaction,
+            event_transaction_lt: parse_u64(&event_transaction_lt)
+                .map_err(|_| "Failed to parse event transaction lt")
+                .handle_error()?,
+            event_timestamp,
+            event_index,
+            event_data: boc_input_to_base64(event_data)
+                .map_err(|_| "Failed to parse event data")
+                .handle_error()?,
+            ton_event_configuration,
+            required_confirmations,
+            required_rejections,
+            configuration_meta: boc_input_to_base64(configuration_meta)
+                .map_err(|_| "Failed to parse configuration meta")
+                .handle_error()?,
+        };
+        parse_init_data(&init_data).handle_error()?;
+        Ok(init_data)
+    }
+
+    #[wasm_bindgen(js_name = "fromJSON")]
+    pub fn from_json(json: &str) -> Result<TonEventInitData, JsValue> {
+        utils::set_panic_hook();
+        let json = serde_json::from_str::<TonEventInitDataJson>(json)
+            .map_err(|_| "Failed to parse event init data")
+            .handle_error()?;
+        let init_data = json.try_into_init_data().handle_error()?;
+        parse_init_data(&init_data).handle_error()?;
+        Ok(init_data)
+    }
+
+    #[wasm_bindgen(js_name = "toJSON")]
+    pub fn to_json(&self) -> Result<TonEventInitDataJsonObject, JsValue> {
+        js_sys::JSON::parse(&self.to_json_value().to_string())
+            .map(JsCast::unchecked_into)
+            .map_err(|_| "Failed to convert event init data")
+            .handle_error()
+    }
+
+    #[wasm_bindgen(getter = eventTransaction)]
+    pub fn event_transaction(&self) -> String {
+        self.event_transaction.clone()
+    }
+
+    #[wasm_bindgen(getter = eventTransactionLt)]
+    pub fn event_transaction_lt(&self) -> String {
+        self.event_transaction_lt.to_string()
+    }
+
+    #[wasm_bindgen(getter = eventTransactionLtBigInt)]
+    pub fn event_transaction_lt_big_int(&self) -> u64 {
+        self.event_transaction_lt
+    }
+
+    #[wasm_bindgen(getter = eventTimestamp)]
+    pub fn event_timestamp(&self) -> u32 {
+        self.event_timestamp
+    }
+
+    #[wasm_bindgen(getter = eventIndex)]
+    pub fn event_index(&self) -> u32 {
+        self.event_index
+    }
+
+    #[wasm_bindgen(getter = eventData)]
+    pub fn event_data(&self) -> String {
+        self.event_data.clone()
+    }
+
+    #[wasm_bindgen(getter = eventDataBytes)]
+    pub fn event_data_bytes(&self) -> Result<Vec<u8>, JsValue> {
+        utils::decode_boc(&self.event_data).handle_error()
+    }
+
+    #[wasm_bindgen(getter = tonEventConfiguration)]
+    pub fn ton_event_configuration(&self) -> String {
+        self.ton_event_configuration.clone()
+    }
+
+    #[wasm_bindgen(getter = requiredConfirmations)]
+    pub fn required_confirmations(&self) -> u16 {
+        self.required_confirmations
+    }
+
+    #[wasm_bindgen(getter = requiredRejections)]
+    pub fn required_rejections(&self) -> u16 {
+        self.required_rejections
+    }
+
+    #[wasm_bindgen(getter = configurationMeta)]
+    pub fn configuration_meta(&self) -> String {
+        self.configuration_meta.clone()
+    }
+
+    #[wasm_bindgen(getter = configurationMetaBytes)]
+    pub fn configuration_meta_bytes(&self) -> Result<Vec<u8>, JsValue> {
+        utils::decode_boc(&self.configuration_meta).handle_error()
+    }
+}
+
+fn convert_init_data(data: contract::TonEventInitData, format: address::AddressFormat) -> Result<TonEventInitData> {
+    let event_data = utils::encode_cell(&data.event_data)?;
+    let configuration_meta = utils::encode_cell(&data.configuration_meta)?;
 
     Ok(TonEventInitData {
         event_transaction: data.event_transaction.to_hex_string(),
-        event_transaction_lt: data.event_transaction_lt.to_string(),
+        event_transaction_lt: data.event_transaction_lt,
         event_timestamp: data.event_timestamp,
         event_index: data.event_index,
         event_data,
-        ton_event_configuration: data.ton_event_configuration.to_string(),
+        ton_event_configuration: address::format_any_address(&data.ton_event_configuration, format),
         required_confirmations: data.required_confirmations.to_u16().ok_or("Invalid ABI")?,
-        required_rejections: data.required_confirmations.to_u16().ok_or("Invalid ABI")?,
+        required_rejections: data.required_rejections.to_u16().ok_or("Invalid ABI")?,
+        configuration_meta,
+    })
+}
+
+impl TonEventInitData {
+    fn to_json_value(&self) -> serde_json::Value {
+        serde_json::json!({
+            "eventTransaction": self.event_transaction,
+            "eventTransactionLt": self.event_transaction_lt.to_string(),
+            "eventTimestamp": self.event_timestamp,
+            "eventIndex": self.event_index,
+            "eventData": self.event_data,
+            "tonEventConfiguration": self.ton_event_configuration,
+            "requiredConfirmations": self.required_confirmations,
+            "requiredRejections": self.required_rejections,
+            "configurationMeta": self.configuration_meta,
+        })
+    }
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TonEventInitDataJson {
+    event_transaction: String,
+    // either decimal string or number
+    event_transaction_lt: serde_json::Value,
+    event_timestamp: u32,
+    event_index: u32,
+    event_data: String,
+    ton_event_configuration: String,
+    required_confirmations: u16,
+    required_rejections: u16,
+    configuration_meta: String,
+}
+
+impl TonEventInitDataJson {
+    fn try_into_init_data(self) -> Result<TonEventInitData> {
+        let event_transaction_lt = match self.event_transaction_lt {
+            serde_json::Value::String(lt) => lt.parse::<u64>().ok(),
+            // same limit as for numbers passed to the constructor
+            serde_json::Value::Number(lt) => lt.as_u64().filter(|lt| *lt as f64 <= MAX_SAFE_INTEGER),
+            _ => None,
+        }
+        .ok_or("Failed to parse event transaction lt")?;
+
+        Ok(TonEventInitData {
+            event_transaction: self.event_transaction,
+            event_transaction_lt,
+            event_timestamp: self.event_timestamp,
+            event_index: self.event_index,
+            event_data: normalize_boc(&self.event_data).map_err(|_| "Failed to parse event data")?,
+            ton_event_configuration: self.ton_event_configuration,
+            required_confirmations: self.required_confirmations,
+            required_rejections: self.required_rejections,
+            configuration_meta: normalize_boc(&self.configuration_meta)
+                .map_err(|_| "Failed to parse configuration meta")?,
+        })
+    }
+}
+
+fn parse_init_data(value: &TonEventInitData) -> Result<contract::TonEventInitData> {
+    let event_transaction =
+        utils::parse_hash(&value.event_transaction).map_err(|_| "Failed to parse event transaction")?;
+
+    let event_data = utils::decode_cell(&value.event_data)?;
+
+    let ton_event_configuration = address::parse_address(&value.ton_event_configuration, None)
+        .map_err(|_| "Failed to parse TON event configuration address")?;
+
+    let configuration_meta = utils::decode_cell(&value.configuration_meta)?;
+
+    Ok(contract::TonEventInitData {
+        event_transaction,
+        event_transaction_lt: value.event_transaction_lt,
+        event_timestamp: value.event_timestamp,
+        event_index: value.event_index,
+        event_data,
+        ton_event_configuration,
+        required_confirmations: value.required_confirmations,
+        required_rejections: value.required_rejections,
         configuration_meta,
     })
 }
 
+/// Event contract status
 #[wasm_bindgen]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq)]
 pub enum EventStatus {
+    /// Event is waiting for relay votes
     InProcess = "in_process",
+    /// Event received enough confirmations
     Confirmed = "confirmed",
+    /// Event received enough rejections
     Rejected = "rejected",
 }
 
+impl EventStatus {
+    const VARIANTS: &'static [(EventStatus, contract::EventStatus)] = &[
+        (EventStatus::InProcess, contract::EventStatus::InProcess),
+        (EventStatus::Confirmed, contract::EventStatus::Confirmed),
+        (EventStatus::Rejected, contract::EventStatus::Rejected),
+    ];
+
+    fn parse(self) -> Result<contract::EventStatus> {
+        map_string_enum(self, Self::VARIANTS).ok_or("Invalid event status")
+    }
+
+    fn from_json_str(value: &str) -> Result<Self> {
+        match value {
+            "in_process" => Ok(EventStatus::InProcess),
+            "confirmed" => Ok(EventStatus::Confirmed),
+            "rejected" => Ok(EventStatus::Rejected),
+            _ => Err("Invalid event status"),
+        }
+    }
+
+    fn json_str(self) -> &'static str {
+        match self.parse() {
+            Ok(contract::EventStatus::InProcess) => "in_process",
+            Ok(contract::EventStatus::Confirmed) => "confirmed",
+            Ok(contract::EventStatus::Rejected) => "rejected",
+            Err(_) => "unknown",
+        }
+    }
+}
+
 impl From<contract::EventStatus> for EventStatus {
     fn from(status: contract::EventStatus) -> Self {
         match status {
@@ -233,20 +1364,992 @@ impl From<contract::EventStatus> for EventStatus {
     }
 }
 
-impl<T> HandleError for Result<T> {
-    type Output = T;
+#[wasm_bindgen]
+pub struct Vote {
+    relay: String,
+    kind: VoteKind,
+    lt: u64,
+    timestamp: u32,
+    status: EventStatus,
+    confirmations: u16,
+    rejections: u16,
+}
 
-    fn handle_error(self) -> Result<Self::Output, JsValue> {
-        self.map_err(|e| js_sys::Error::new(&e.to_string()).into())
+#[wasm_bindgen]
+impl Vote {
+    #[wasm_bindgen(getter)]
+    pub fn relay(&self) -> String {
+        self.relay.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn kind(&self) -> VoteKind {
+        self.kind
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn lt(&self) -> String {
+        self.lt.to_string()
+    }
+
+    #[wasm_bindgen(getter = ltBigInt)]
+    pub fn lt_big_int(&self) -> u64 {
+        self.lt
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn timestamp(&self) -> u32 {
+        self.timestamp
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn status(&self) -> EventStatus {
+        self.status
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn confirmations(&self) -> u16 {
+        self.confirmations
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn rejections(&self) -> u16 {
+        self.rejections
     }
 }
 
-impl<T> HandleError for ton_types::Result<T> {
-    type Output = T;
+fn parse_vote_functions(functions: Option<VoteFunctionsInput>) -> Result<history::VoteFunctions> {
+    let mut result = history::VoteFunctions::default();
+    let functions = match functions {
+        Some(functions) => JsValue::from(functions),
+        None => return Ok(result),
+    };
 
-    fn handle_error(self) -> Result<Self::Output, JsValue> {
-        self.map_err(|e| js_sys::Error::new(&e.to_string()).into())
+    let field = |name: &str| {
+        js_sys::Reflect::get(&functions, &JsValue::from(name))
+            .map(|value| value.as_string())
+            .map_err(|_| "Invalid vote functions")
+    };
+
+    if let Some(deploy) = field("deploy")? {
+        result.deploy = deploy;
+    }
+    if let Some(confirm) = field("confirm")? {
+        result.confirm = confirm;
+    }
+    if let Some(reject) = field("reject")? {
+        result.reject = reject;
+    }
+    Ok(result)
+}
+
+fn convert_vote(vote: history::Vote, format: address::AddressFormat) -> Vote {
+    Vote {
+        relay: address::format_any_address(&vote.relay, format),
+        kind: vote.kind.into(),
+        lt: vote.lt,
+        timestamp: vote.timestamp,
+        status: vote.status.into(),
+        confirmations: vote.confirmations,
+        rejections: vote.rejections,
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub enum VoteKind {
+    /// Event contract deployment, counted as a confirmation
+    Deploy = "deploy",
+    Confirm = "confirm",
+    Reject = "reject",
+}
+
+impl From<history::VoteKind> for VoteKind {
+    fn from(kind: history::VoteKind) -> Self {
+        match kind {
+            history::VoteKind::Deploy => VoteKind::Deploy,
+            history::VoteKind::Confirm => VoteKind::Confirm,
+            history::VoteKind::Reject => VoteKind::Reject,
+        }
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct ConfigurationRegistry {
+    inner: registry::ConfigurationRegistry,
+    address_format: address::AddressFormat,
+}
+
+#[wasm_bindgen]
+impl ConfigurationRegistry {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> ConfigurationRegistry {
+        ConfigurationRegistry::default()
+    }
+
+    #[wasm_bindgen(js_name = "fromJSON")]
+    pub fn from_json(json: &str) -> Result<ConfigurationRegistry, JsValue> {
+        utils::set_panic_hook();
+        let inner = registry::ConfigurationRegistry::from_json(json).handle_error()?;
+        Ok(ConfigurationRegistry {
+            inner,
+            address_format: Default::default(),
+        })
+    }
+
+    #[wasm_bindgen(js_name = "fromTOML")]
+    pub fn from_toml(toml: &str) -> Result<ConfigurationRegistry, JsValue> {
+        utils::set_panic_hook();
+        let inner = registry::ConfigurationRegistry::from_toml(toml).handle_error()?;
+        Ok(ConfigurationRegistry {
+            inner,
+            address_format: Default::default(),
+        })
+    }
+
+    /// Makes lookups fail for configurations of other chains, `undefined` disables the check
+    #[wasm_bindgen(js_name = "setChainId")]
+    pub fn set_chain_id(&mut self, chain_id: Option<u32>) {
+        self.inner.set_chain_id(chain_id);
+    }
+
+    pub fn get(&self, configuration: &str) -> Result<ConfigurationEntry, JsValue> {
+        let configuration = address::parse_address(configuration, None)
+            .map_err(|_| "Failed to parse TON event configuration address")
+            .handle_error()?;
+        let entry = self.inner.get(&configuration).handle_error()?;
+        Ok(ConfigurationEntry { inner: entry.clone() })
+    }
+
+    #[wasm_bindgen(js_name = "encodePayload")]
+    pub fn encode_payload(&self, event: &TonEventDetails) -> Result<String, JsValue> {
+        utils::set_panic_hook();
+        let entry = self.find_entry(event).handle_error()?;
+        let payload = convert_eth_payload(event, entry.proxy_address).handle_error()?;
+        eth::encode_eth_payload(payload, &entry.event_abi)
+            .map(|payload| hex::encode(&payload))
+            .handle_error()
+    }
+
+    #[wasm_bindgen(js_name = "decodeEventData")]
+    pub fn decode_event_data(&self, event: &TonEventDetails) -> Result<String, JsValue> {
+        utils::set_panic_hook();
+        let entry = self.find_entry(event).handle_error()?;
+        let event_data = utils::decode_cell(&event.init_data.event_data).handle_error()?;
+        let tokens = eth::decode_event_data(event_data, &entry.event_abi).handle_error()?;
+        let json = decode::tokens_to_json(&tokens, self.address_format).handle_error()?;
+        Ok(json.to_string())
+    }
+
+    /// Same as `decodeEventData` but returns an object with integers as `BigInt`
+    #[wasm_bindgen(js_name = "decodeEventDataObject")]
+    pub fn decode_event_data_object(&self, event: &TonEventDetails) -> Result<DecodedEventDataObject, JsValue> {
+        utils::set_panic_hook();
+        let entry = self.find_entry(event).handle_error()?;
+        let event_data = utils::decode_cell(&event.init_data.event_data).handle_error()?;
+        let tokens = eth::decode_event_data(event_data, &entry.event_abi).handle_error()?;
+        decode::tokens_to_value::<JsValue>(&tokens, self.address_format)
+            .map(JsCast::unchecked_into)
+            .handle_error()
+    }
+
+    #[wasm_bindgen(js_name = "setAddressFormat")]
+    pub fn set_address_format(&mut self, format: &AddressFormat) {
+        self.address_format = format.inner;
+    }
+
+    pub fn summarize(&self, event: &TonEventDetails) -> Result<Option<EventSummary>, JsValue> {
+        utils::set_panic_hook();
+        let entry = self.find_entry(event).handle_error()?;
+        let event_data = utils::decode_cell(&event.init_data.event_data).handle_error()?;
+        summary::summarize_event(event_data, &entry.event_abi)
+            .handle_error()?
+            .map(|summary| {
+                convert_event_summary(
+                    summary,
+                    entry.metadata.decimals,
+                    entry.metadata.symbol.clone(),
+                    self.address_format,
+                )
+            })
+            .transpose()
+            .handle_error()
+    }
+}
+
+impl ConfigurationRegistry {
+    fn find_entry(&self, event: &TonEventDetails) -> Result<&registry::ConfigurationEntry> {
+        let configuration = address::parse_address(&event.init_data.ton_event_configuration, None)
+            .map_err(|_| "Failed to parse TON event configuration address")?;
+        self.inner.get(&configuration)
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Copy, Default)]
+pub struct AddressFormat {
+    inner: address::AddressFormat,
+}
+
+#[wasm_bindgen]
+impl AddressFormat {
+    pub fn raw() -> AddressFormat {
+        AddressFormat::default()
+    }
+
+    pub fn bounceable(url_safe: bool, testnet: bool) -> AddressFormat {
+        AddressFormat {
+            inner: address::AddressFormat::UserFriendly {
+                bounceable: true,
+                url_safe,
+                testnet,
+            },
+        }
+    }
+
+    #[wasm_bindgen(js_name = "nonBounceable")]
+    pub fn non_bounceable(url_safe: bool, testnet: bool) -> AddressFormat {
+        AddressFormat {
+            inner: address::AddressFormat::UserFriendly {
+                bounceable: false,
+                url_safe,
+                testnet,
+            },
+        }
+    }
+
+    #[wasm_bindgen(getter = userFriendly)]
+    pub fn is_user_friendly(&self) -> bool {
+        self.inner != address::AddressFormat::Raw
+    }
+
+    #[wasm_bindgen(getter = bounceable)]
+    pub fn is_bounceable(&self) -> bool {
+        matches!(
+            self.inner,
+            address::AddressFormat::UserFriendly { bounceable: true, .. }
+        )
+    }
+
+    #[wasm_bindgen(getter = urlSafe)]
+    pub fn is_url_safe(&self) -> bool {
+        self.inner.url_safe()
+    }
+
+    #[wasm_bindgen(getter = testnet)]
+    pub fn is_testnet(&self) -> bool {
+        matches!(self.inner, address::AddressFormat::UserFriendly { testnet: true, .. })
+    }
+}
+
+/// Reads `AddressFormat` or `AddressFormatOptions`, both expose the same properties.
+/// Addresses are raw if the format is omitted
+fn parse_address_format(format: Option<AddressFormatInput>) -> Result<address::AddressFormat> {
+    let format = match format {
+        Some(format) => JsValue::from(format),
+        None => return Ok(address::AddressFormat::Raw),
+    };
+    let flag = |name: &str| {
+        js_sys::Reflect::get(&format, &JsValue::from(name))
+            .map(|value| value.as_bool().unwrap_or_default())
+            .map_err(|_| "Invalid address format")
+    };
+
+    Ok(if flag("userFriendly")? {
+        address::AddressFormat::UserFriendly {
+            bounceable: flag("bounceable")?,
+            url_safe: flag("urlSafe")?,
+            testnet: flag("testnet")?,
+        }
+    } else {
+        address::AddressFormat::Raw
+    })
+}
+
+#[wasm_bindgen]
+pub struct EventSummary {
+    kind: EventKind,
+    token_burn: Option<TokenBurnSummary>,
+}
+
+#[wasm_bindgen]
+impl EventSummary {
+    #[wasm_bindgen(getter)]
+    pub fn kind(&self) -> EventKind {
+        self.kind
     }
+
+    #[wasm_bindgen(getter = tokenBurn)]
+    pub fn token_burn(&self) -> Option<TokenBurnSummary> {
+        self.token_burn.clone()
+    }
+}
+
+fn convert_event_summary(
+    value: summary::EventSummary,
+    decimals: Option<u8>,
+    symbol: Option<String>,
+    format: address::AddressFormat,
+) -> Result<EventSummary> {
+    let friendly_format = match format {
+        address::AddressFormat::Raw => address::AddressFormat::UserFriendly {
+            bounceable: true,
+            url_safe: true,
+            testnet: false,
+        },
+        format => format,
+    };
+
+    Ok(match value {
+        summary::EventSummary::TokenBurn(token_burn) => EventSummary {
+            kind: EventKind::TokenBurn,
+            token_burn: Some(TokenBurnSummary {
+                sender_address: token_burn.sender.to_string(),
+                sender_address_friendly: address::format_address(&token_burn.sender, friendly_format)?,
+                amount_formatted: decimals.map(|decimals| summary::format_amount(&token_burn.amount, decimals)),
+                amount: token_burn.amount,
+                decimals,
+                symbol,
+                ethereum_address: utils::format_eth_address(&token_burn.ethereum_address),
+            }),
+        },
+    })
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub enum EventKind {
+    TokenBurn = "token_burn",
+}
+
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct TokenBurnSummary {
+    sender_address: String,
+    sender_address_friendly: String,
+    amount: BigUint,
+    amount_formatted: Option<String>,
+    decimals: Option<u8>,
+    symbol: Option<String>,
+    ethereum_address: String,
+}
+
+#[wasm_bindgen]
+impl TokenBurnSummary {
+    #[wasm_bindgen(getter = senderAddress)]
+    pub fn sender_address(&self) -> String {
+        self.sender_address.clone()
+    }
+
+    #[wasm_bindgen(getter = senderAddressFriendly)]
+    pub fn sender_address_friendly(&self) -> String {
+        self.sender_address_friendly.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn amount(&self) -> String {
+        self.amount.to_string()
+    }
+
+    #[wasm_bindgen(getter = amountBigInt)]
+    pub fn amount_big_int(&self) -> Result<js_sys::BigInt, JsValue> {
+        make_big_int(&self.amount).handle_error()
+    }
+
+    #[wasm_bindgen(getter = amountFormatted)]
+    pub fn amount_formatted(&self) -> Option<String> {
+        self.amount_formatted.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn decimals(&self) -> Option<u8> {
+        self.decimals
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn symbol(&self) -> Option<String> {
+        self.symbol.clone()
+    }
+
+    #[wasm_bindgen(getter = ethereumAddress)]
+    pub fn ethereum_address(&self) -> String {
+        self.ethereum_address.clone()
+    }
+}
+
+#[wasm_bindgen]
+pub struct ConfigurationEntry {
+    inner: registry::ConfigurationEntry,
+}
+
+#[wasm_bindgen]
+impl ConfigurationEntry {
+    #[wasm_bindgen(getter = eventAbi)]
+    pub fn event_abi(&self) -> String {
+        self.inner.event_abi.clone()
+    }
+
+    #[wasm_bindgen(getter = proxyAddress)]
+    pub fn proxy_address(&self) -> String {
+        utils::format_eth_address(&self.inner.proxy_address)
+    }
+
+    #[wasm_bindgen(getter = chainId)]
+    pub fn chain_id(&self) -> u32 {
+        self.inner.chain_id
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn name(&self) -> Option<String> {
+        self.inner.metadata.name.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn description(&self) -> Option<String> {
+        self.inner.metadata.description.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn symbol(&self) -> Option<String> {
+        self.inner.metadata.symbol.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn decimals(&self) -> Option<u8> {
+        self.inner.metadata.decimals
+    }
+}
+
+#[wasm_bindgen]
+pub struct TonEventConfigurationDetails {
+    basic_init_data: BasicConfigurationInitData,
+    event_address: String,
+    proxy_address: String,
+    start_timestamp: u32,
+}
+
+#[wasm_bindgen]
+impl TonEventConfigurationDetails {
+    #[wasm_bindgen(getter = basicInitData)]
+    pub fn basic_init_data(&self) -> BasicConfigurationInitData {
+        self.basic_init_data.clone()
+    }
+
+    #[wasm_bindgen(getter = eventAddress)]
+    pub fn event_address(&self) -> String {
+        self.event_address.clone()
+    }
+
+    #[wasm_bindgen(getter = proxyAddress)]
+    pub fn proxy_address(&self) -> String {
+        self.proxy_address.clone()
+    }
+
+    #[wasm_bindgen(getter = startTimestamp)]
+    pub fn start_timestamp(&self) -> u32 {
+        self.start_timestamp
+    }
+}
+
+#[wasm_bindgen]
+pub struct EthereumEventConfigurationDetails {
+    basic_init_data: BasicConfigurationInitData,
+    event_address: String,
+    event_blocks_to_confirm: u16,
+    proxy_address: String,
+    start_block_number: u32,
+}
+
+#[wasm_bindgen]
+impl EthereumEventConfigurationDetails {
+    #[wasm_bindgen(getter = basicInitData)]
+    pub fn basic_init_data(&self) -> BasicConfigurationInitData {
+        self.basic_init_data.clone()
+    }
+
+    #[wasm_bindgen(getter = eventAddress)]
+    pub fn event_address(&self) -> String {
+        self.event_address.clone()
+    }
+
+    #[wasm_bindgen(getter = eventBlocksToConfirm)]
+    pub fn event_blocks_to_confirm(&self) -> u16 {
+        self.event_blocks_to_confirm
+    }
+
+    #[wasm_bindgen(getter = proxyAddress)]
+    pub fn proxy_address(&self) -> String {
+        self.proxy_address.clone()
+    }
+
+    #[wasm_bindgen(getter = startBlockNumber)]
+    pub fn start_block_number(&self) -> u32 {
+        self.start_block_number
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct BasicConfigurationInitData {
+    event_abi: String,
+    event_required_confirmations: u16,
+    event_required_rejections: u16,
+    event_code: String,
+    bridge_address: String,
+    event_initial_balance: BigUint,
+    meta: String,
+}
+
+#[wasm_bindgen]
+impl BasicConfigurationInitData {
+    #[wasm_bindgen(getter = eventAbi)]
+    pub fn event_abi(&self) -> String {
+        self.event_abi.clone()
+    }
+
+    #[wasm_bindgen(getter = eventRequiredConfirmations)]
+    pub fn event_required_confirmations(&self) -> u16 {
+        self.event_required_confirmations
+    }
+
+    #[wasm_bindgen(getter = eventRequiredRejections)]
+    pub fn event_required_rejections(&self) -> u16 {
+        self.event_required_rejections
+    }
+
+    #[wasm_bindgen(getter = eventCode)]
+    pub fn event_code(&self) -> String {
+        self.event_code.clone()
+    }
+
+    #[wasm_bindgen(getter = eventCodeBytes)]
+    pub fn event_code_bytes(&self) -> Result<Vec<u8>, JsValue> {
+        utils::decode_boc(&self.event_code).handle_error()
+    }
+
+    #[wasm_bindgen(getter = bridgeAddress)]
+    pub fn bridge_address(&self) -> String {
+        self.bridge_address.clone()
+    }
+
+    #[wasm_bindgen(getter = eventInitialBalance)]
+    pub fn event_initial_balance(&self) -> String {
+        self.event_initial_balance.to_string()
+    }
+
+    #[wasm_bindgen(getter = eventInitialBalanceBigInt)]
+    pub fn event_initial_balance_big_int(&self) -> Result<js_sys::BigInt, JsValue> {
+        make_big_int(&self.event_initial_balance).handle_error()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn meta(&self) -> String {
+        self.meta.clone()
+    }
+
+    #[wasm_bindgen(getter = metaBytes)]
+    pub fn meta_bytes(&self) -> Result<Vec<u8>, JsValue> {
+        utils::decode_boc(&self.meta).handle_error()
+    }
+}
+
+fn convert_basic_configuration_init_data(
+    data: configuration::BasicConfigurationInitData,
+    format: address::AddressFormat,
+) -> Result<BasicConfigurationInitData> {
+    Ok(BasicConfigurationInitData {
+        event_abi: data.event_abi,
+        event_required_confirmations: data.event_required_confirmations,
+        event_required_rejections: data.event_required_rejections,
+        event_code: utils::encode_cell(&data.event_code)?,
+        bridge_address: address::format_address(&data.bridge_address, format)?,
+        event_initial_balance: data.event_initial_balance,
+        meta: utils::encode_cell(&data.meta)?,
+    })
+}
+
+#[wasm_bindgen]
+pub struct BridgeDetails {
+    configuration: bridge::BridgeConfiguration,
+    relays: Vec<BridgeRelay>,
+}
+
+#[wasm_bindgen]
+impl BridgeDetails {
+    #[wasm_bindgen(getter = eventConfigurationRequiredConfirmations)]
+    pub fn event_configuration_required_confirmations(&self) -> u16 {
+        self.configuration.event_configuration_required_confirmations
+    }
+
+    #[wasm_bindgen(getter = eventConfigurationRequiredRejections)]
+    pub fn event_configuration_required_rejections(&self) -> u16 {
+        self.configuration.event_configuration_required_rejections
+    }
+
+    #[wasm_bindgen(getter = bridgeConfigurationUpdateRequiredConfirmations)]
+    pub fn bridge_configuration_update_required_confirmations(&self) -> u16 {
+        self.configuration.bridge_configuration_update_required_confirmations
+    }
+
+    #[wasm_bindgen(getter = bridgeConfigurationUpdateRequiredRejections)]
+    pub fn bridge_configuration_update_required_rejections(&self) -> u16 {
+        self.configuration.bridge_configuration_update_required_rejections
+    }
+
+    #[wasm_bindgen(getter = bridgeRelayUpdateRequiredConfirmations)]
+    pub fn bridge_relay_update_required_confirmations(&self) -> u16 {
+        self.configuration.bridge_relay_update_required_confirmations
+    }
+
+    #[wasm_bindgen(getter = bridgeRelayUpdateRequiredRejections)]
+    pub fn bridge_relay_update_required_rejections(&self) -> u16 {
+        self.configuration.bridge_relay_update_required_rejections
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn active(&self) -> bool {
+        self.configuration.active
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn relays(&self) -> BridgeRelayArray {
+        self.relays
+            .iter()
+            .cloned()
+            .map(JsValue::from)
+            .collect::<js_sys::Array>()
+            .unchecked_into()
+    }
+
+    #[wasm_bindgen(js_name = "toRelaySet")]
+    pub fn to_relay_set(&self) -> Result<RelaySet, JsValue> {
+        let mut relays = RelaySet::new();
+        for relay in &self.relays {
+            relays.add(&relay.ton_address, &relay.eth_address)?;
+        }
+        Ok(relays)
+    }
+}
+
+fn convert_bridge_details(details: bridge::BridgeDetails, format: address::AddressFormat) -> Result<BridgeDetails> {
+    Ok(BridgeDetails {
+        configuration: details.configuration,
+        relays: details
+            .relays
+            .into_iter()
+            .map(|relay| {
+                Ok(BridgeRelay {
+                    ton_address: address::format_address(&relay.ton_address, format)?,
+                    eth_address: utils::format_eth_address(&relay.eth_address),
+                })
+            })
+            .collect::<Result<_>>()?,
+    })
+}
+
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct BridgeRelay {
+    ton_address: String,
+    eth_address: String,
+}
+
+#[wasm_bindgen]
+impl BridgeRelay {
+    #[wasm_bindgen(getter = tonAddress)]
+    pub fn ton_address(&self) -> String {
+        self.ton_address.clone()
+    }
+
+    #[wasm_bindgen(getter = ethAddress)]
+    pub fn eth_address(&self) -> String {
+        self.eth_address.clone()
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct RelaySet {
+    relays: Vec<quorum::Relay>,
+}
+
+#[wasm_bindgen]
+impl RelaySet {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> RelaySet {
+        RelaySet::default()
+    }
+
+    pub fn add(&mut self, ton_address: &str, eth_address: &str, strict: Option<bool>) -> Result<(), JsValue> {
+        let ton_address = address::parse_address(ton_address, None)
+            .map_err(|_| "Failed to parse relay address")
+            .handle_error()?;
+        let eth_address = utils::parse_eth_address_with(eth_address, strict.unwrap_or_default()).handle_error()?;
+        self.relays.push(quorum::Relay {
+            ton_address,
+            eth_address,
+        });
+        Ok(())
+    }
+}
+
+#[wasm_bindgen]
+pub struct QuorumAnalysis {
+    confirmations: u32,
+    rejections: u32,
+    required_confirmations: u16,
+    required_rejections: u16,
+    not_voted: Vec<String>,
+    invalid_signatures: Vec<String>,
+    duplicate_votes: Vec<String>,
+    unknown_relays: Vec<String>,
+    status_consistent: bool,
+}
+
+#[wasm_bindgen]
+impl QuorumAnalysis {
+    #[wasm_bindgen(getter)]
+    pub fn confirmations(&self) -> u32 {
+        self.confirmations
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn rejections(&self) -> u32 {
+        self.rejections
+    }
+
+    #[wasm_bindgen(getter = requiredConfirmations)]
+    pub fn required_confirmations(&self) -> u16 {
+        self.required_confirmations
+    }
+
+    #[wasm_bindgen(getter = requiredRejections)]
+    pub fn required_rejections(&self) -> u16 {
+        self.required_rejections
+    }
+
+    #[wasm_bindgen(getter = notVoted)]
+    pub fn not_voted(&self) -> TonAddressArray {
+        self.not_voted
+            .iter()
+            .map(JsValue::from)
+            .collect::<js_sys::Array>()
+            .unchecked_into()
+    }
+
+    #[wasm_bindgen(getter = invalidSignatures)]
+    pub fn invalid_signatures(&self) -> TonAddressArray {
+        self.invalid_signatures
+            .iter()
+            .map(JsValue::from)
+            .collect::<js_sys::Array>()
+            .unchecked_into()
+    }
+
+    #[wasm_bindgen(getter = duplicateVotes)]
+    pub fn duplicate_votes(&self) -> TonAddressArray {
+        self.duplicate_votes
+            .iter()
+            .map(JsValue::from)
+            .collect::<js_sys::Array>()
+            .unchecked_into()
+    }
+
+    #[wasm_bindgen(getter = unknownRelays)]
+    pub fn unknown_relays(&self) -> TonAddressArray {
+        self.unknown_relays
+            .iter()
+            .map(JsValue::from)
+            .collect::<js_sys::Array>()
+            .unchecked_into()
+    }
+
+    #[wasm_bindgen(getter = statusConsistent)]
+    pub fn status_consistent(&self) -> bool {
+        self.status_consistent
+    }
+}
+
+#[wasm_bindgen]
+pub struct CodeHashAllowlist {
+    inner: authenticity::CodeHashAllowlist,
+}
+
+#[wasm_bindgen]
+impl CodeHashAllowlist {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> CodeHashAllowlist {
+        CodeHashAllowlist {
+            inner: Default::default(),
+        }
+    }
+
+    #[wasm_bindgen(js_name = "fromJSON")]
+    pub fn from_json(json: &str) -> Result<CodeHashAllowlist, JsValue> {
+        let inner = authenticity::CodeHashAllowlist::from_json(json).handle_error()?;
+        Ok(CodeHashAllowlist { inner })
+    }
+
+    pub fn insert(&mut self, network: String, direction: EventDirection, code_hash: &str) -> Result<(), JsValue> {
+        let direction = direction.parse().handle_error()?;
+        let code_hash = utils::parse_hash(code_hash).handle_error()?;
+        self.inner.insert(network, direction, code_hash);
+        Ok(())
+    }
+}
+
+impl Default for CodeHashAllowlist {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[wasm_bindgen]
+pub struct CodeFunctions {
+    found: Vec<CodeFunction>,
+    missing: Vec<CodeFunction>,
+    unknown: Vec<u32>,
+}
+
+#[wasm_bindgen]
+impl CodeFunctions {
+    #[wasm_bindgen(getter)]
+    pub fn found(&self) -> CodeFunctionArray {
+        self.found
+            .iter()
+            .cloned()
+            .map(JsValue::from)
+            .collect::<js_sys::Array>()
+            .unchecked_into()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn missing(&self) -> CodeFunctionArray {
+        self.missing
+            .iter()
+            .cloned()
+            .map(JsValue::from)
+            .collect::<js_sys::Array>()
+            .unchecked_into()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn unknown(&self) -> NumberArray {
+        self.unknown
+            .iter()
+            .copied()
+            .map(JsValue::from)
+            .collect::<js_sys::Array>()
+            .unchecked_into()
+    }
+}
+
+impl From<functions::CodeFunctions> for CodeFunctions {
+    fn from(functions: functions::CodeFunctions) -> Self {
+        let convert = |functions: Vec<functions::KnownFunction>| {
+            functions
+                .into_iter()
+                .map(|function| CodeFunction {
+                    name: function.name,
+                    id: function.id,
+                })
+                .collect()
+        };
+
+        Self {
+            found: convert(functions.found),
+            missing: convert(functions.missing),
+            unknown: functions.unknown,
+        }
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct CodeFunction {
+    name: String,
+    id: u32,
+}
+
+#[wasm_bindgen]
+impl CodeFunction {
+    #[wasm_bindgen(getter)]
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Copy, PartialEq)]
+pub enum EventDirection {
+    TonToEth = "ton_to_eth",
+    EthToTon = "eth_to_ton",
+}
+
+impl EventDirection {
+    const VARIANTS: &'static [(EventDirection, authenticity::EventDirection)] = &[
+        (EventDirection::TonToEth, authenticity::EventDirection::TonToEth),
+        (EventDirection::EthToTon, authenticity::EventDirection::EthToTon),
+    ];
+
+    fn parse(self) -> Result<authenticity::EventDirection> {
+        map_string_enum(self, Self::VARIANTS).ok_or("Invalid event direction")
+    }
+}
+
+/// String enums generated by wasm-bindgen have a hidden variant for invalid values passed
+/// from JS, so they can't be matched exhaustively and are converted through variant tables
+fn map_string_enum<T: PartialEq, U: Copy>(value: T, variants: &[(T, U)]) -> Option<U> {
+    variants
+        .iter()
+        .find(|(variant, _)| *variant == value)
+        .map(|(_, mapped)| *mapped)
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub enum Authenticity {
+    Trusted = "trusted",
+    Unknown = "unknown",
+    Mismatched = "mismatched",
+}
+
+impl From<authenticity::Authenticity> for Authenticity {
+    fn from(authenticity: authenticity::Authenticity) -> Self {
+        match authenticity {
+            authenticity::Authenticity::Trusted => Authenticity::Trusted,
+            authenticity::Authenticity::Unknown => Authenticity::Unknown,
+            authenticity::Authenticity::Mismatched => Authenticity::Mismatched,
+        }
+    }
+}
+
+impl<T> HandleError for Result<T> {
+    type Output = T;
+
+    fn handle_error(self) -> Result<Self::Output, JsValue> {
+        self.map_err(make_error)
+    }
+}
+
+impl<T> HandleError for ton_types::Result<T> {
+    type Output = T;
+
+    fn handle_error(self) -> Result<Self::Output, JsValue> {
+        self.map_err(|e| make_error(&e.to_string()))
+    }
+}
+
+fn make_error(message: &str) -> JsValue {
+    let error = js_sys::Error::new(message);
+    error.set_name("TonEventDetailsError");
+    error.into()
 }
 
 trait HandleError {
@@ -0,0 +1,100 @@
+use ton_block::MsgAddressInt;
+
+use crate::contract::{EventStatus, TonEventDetails};
+use crate::eth;
+use crate::signature::Signature;
+
+pub struct Relay {
+    pub ton_address: MsgAddressInt,
+    pub eth_address: ethabi::Address,
+}
+
+pub struct QuorumAnalysis {
+    pub confirmations: usize,
+    pub rejections: usize,
+    pub required_confirmations: u16,
+    pub required_rejections: u16,
+    pub not_voted: Vec<MsgAddressInt>,
+    pub invalid_signatures: Vec<MsgAddressInt>,
+    pub duplicate_votes: Vec<MsgAddressInt>,
+    pub unknown_relays: Vec<MsgAddressInt>,
+    pub status_consistent: bool,
+}
+
+/// Checks relay votes of the event against the known relays.
+///
+/// `details.signatures[i]` is expected to be the signature of `details.confirms[i]`, a confirm
+/// without a matching signature is reported as invalid. Signatures are checked against `payload`
+/// as produced by [`eth::encode_eth_payload`], see [`eth::recover_signer`] for the message format.
+pub fn analyze_quorum(details: &TonEventDetails, relays: &[Relay], payload: &[u8]) -> QuorumAnalysis {
+    let required_confirmations = details.init_data.required_confirmations;
+    let required_rejections = details.init_data.required_rejections;
+
+    let mut voted = Vec::new();
+    let mut duplicate_votes = Vec::new();
+    let mut unknown_relays = Vec::new();
+    for relay in details.confirms.iter().chain(details.rejections.iter()) {
+        if voted.contains(&relay) {
+            if !duplicate_votes.contains(relay) {
+                duplicate_votes.push(relay.clone());
+            }
+            continue;
+        }
+        voted.push(relay);
+
+        if !relays.iter().any(|item| &item.ton_address == relay) {
+            unknown_relays.push(relay.clone());
+        }
+    }
+
+    let not_voted = relays
+        .iter()
+        .filter(|relay| !voted.contains(&&relay.ton_address))
+        .map(|relay| relay.ton_address.clone())
+        .collect();
+
+    // signatures are stored in the same order as confirmations
+    let invalid_signatures = details
+        .confirms
+        .iter()
+        .enumerate()
+        .filter(|(i, address)| {
+            let relay = match relays.iter().find(|relay| &relay.ton_address == *address) {
+                Some(relay) => relay,
+                None => return false,
+            };
+
+            match details.signatures.get(*i) {
+                Some(signature) => !matches!(
+                    Signature::parse(signature).and_then(|signature| eth::recover_signer(payload, &signature.to_bytes())),
+                    Ok(signer) if signer == relay.eth_address
+                ),
+                None => true,
+            }
+        })
+        .map(|(_, address)| address.clone())
+        .collect();
+
+    let confirmations = details.confirms.len();
+    let rejections = details.rejections.len();
+
+    let expected_status = if confirmations >= required_confirmations as usize {
+        EventStatus::Confirmed
+    } else if rejections >= required_rejections as usize {
+        EventStatus::Rejected
+    } else {
+        EventStatus::InProcess
+    };
+
+    QuorumAnalysis {
+        confirmations,
+        rejections,
+        required_confirmations,
+        required_rejections,
+        not_voted,
+        invalid_signatures,
+        duplicate_votes,
+        unknown_relays,
+        status_consistent: expected_status == details.status,
+    }
+}
@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Deserializer};
+use ton_block::MsgAddressInt;
+
+use crate::address;
+use crate::utils::{self, Result};
+
+#[derive(Debug, Clone, Default)]
+pub struct ConfigurationRegistry {
+    configurations: HashMap<String, ConfigurationEntry>,
+    chain_id: Option<u32>,
+}
+
+impl ConfigurationRegistry {
+    pub fn from_json(json: &str) -> Result<Self> {
+        let entries = serde_json::from_str::<HashMap<String, ConfigurationEntry>>(json)
+            .map_err(|_| "Failed to parse configuration registry")?;
+        Self::from_entries(entries)
+    }
+
+    pub fn from_toml(toml: &str) -> Result<Self> {
+        let entries = toml::from_str::<HashMap<String, ConfigurationEntry>>(toml)
+            .map_err(|_| "Failed to parse configuration registry")?;
+        Self::from_entries(entries)
+    }
+
+    fn from_entries(entries: HashMap<String, ConfigurationEntry>) -> Result<Self> {
+        let mut registry = Self::default();
+        for (address, entry) in entries {
+            let address = address::parse_address(&address, None).map_err(|_| "Invalid configuration address")?;
+            registry.insert(&address, entry);
+        }
+        Ok(registry)
+    }
+
+    pub fn insert(&mut self, address: &MsgAddressInt, entry: ConfigurationEntry) {
+        self.configurations.insert(address.to_string(), entry);
+    }
+
+    /// Restricts lookups to the configurations of the specified Ethereum chain
+    pub fn set_chain_id(&mut self, chain_id: Option<u32>) {
+        self.chain_id = chain_id;
+    }
+
+    pub fn get(&self, address: &MsgAddressInt) -> Result<&ConfigurationEntry> {
+        let entry = self
+            .configurations
+            .get(&address.to_string())
+            .ok_or("Unknown event configuration")?;
+        match self.chain_id {
+            Some(chain_id) if chain_id != entry.chain_id => Err("Event configuration belongs to another chain"),
+            _ => Ok(entry),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ConfigurationEntry {
+    #[serde(deserialize_with = "deserialize_event_abi")]
+    pub event_abi: String,
+    #[serde(deserialize_with = "deserialize_eth_address")]
+    pub proxy_address: ethabi::Address,
+    pub chain_id: u32,
+    #[serde(default)]
+    pub metadata: ConfigurationMetadata,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ConfigurationMetadata {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub symbol: Option<String>,
+    pub decimals: Option<u8>,
+}
+
+// event ABI can be specified either as a JSON string or as an object
+fn deserialize_event_abi<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::String(abi) => Ok(abi),
+        abi => Ok(abi.to_string()),
+    }
+}
+
+fn deserialize_eth_address<'de, D>(deserializer: D) -> Result<ethabi::Address, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let address = String::deserialize(deserializer)?;
+    utils::parse_eth_address(&address).map_err(serde::de::Error::custom)
+}
@@ -0,0 +1,72 @@
+use std::convert::TryFrom;
+
+use ton_block::{Deserializable, HashmapAugType, MsgAddressInt, ShardAccount, ShardAccounts, ShardIdent};
+use ton_types::{SliceData, UInt256};
+
+use crate::contract::{self, TonEventDetails};
+use crate::utils::{self, Result};
+
+/// Calls `f` for each account with a matching code hash without collecting the results.
+/// Accounts that fail to decode are passed to `f` with an error.
+///
+/// Only the state header and the accounts dictionary are read. Dictionary nodes and
+/// accounts are deserialized one by one during iteration, other parts of the state are skipped
+pub fn iterate_event_accounts<F>(shard_state: &[u8], code_hashes: &[UInt256], mut f: F) -> Result<()>
+where
+    F: FnMut(MsgAddressInt, Result<TonEventDetails>) -> bool,
+{
+    let (workchain_id, accounts) = read_shard_accounts(shard_state)?;
+
+    accounts
+        .iterate_with_keys(|id: UInt256, shard_account: ShardAccount| {
+            let account = match shard_account.read_account() {
+                Ok(account) => account,
+                Err(_) => {
+                    let address = MsgAddressInt::with_standart(
+                        None,
+                        workchain_id,
+                        SliceData::from_raw(id.as_slice().to_vec(), 256),
+                    )?;
+                    return Ok(f(address, Err("Failed to decode account")));
+                }
+            };
+
+            let address = match (account.get_addr(), account.get_code()) {
+                (Some(address), Some(code)) if code_hashes.contains(&code.repr_hash()) => address.clone(),
+                _ => return Ok(true),
+            };
+
+            let details = utils::decode_account(&account).and_then(|(code, data)| contract::get_details(code, data));
+            Ok(f(address, details))
+        })
+        .map_err(|_| "Failed to iterate shard state accounts")?;
+
+    Ok(())
+}
+
+/// Reads the `accounts` reference of `ShardStateUnsplit` without deserializing the rest of the state
+fn read_shard_accounts(shard_state: &[u8]) -> Result<(i8, ShardAccounts)> {
+    const INVALID_SHARD_STATE: &str = "Failed to decode shard state";
+
+    let root = utils::deserialize_cell(shard_state).map_err(|_| INVALID_SHARD_STATE)?;
+
+    let mut header = SliceData::from(root.clone());
+    match header.get_next_u32() {
+        Ok(SHARD_STATE_TAG) => {}
+        _ => return Err(INVALID_SHARD_STATE),
+    }
+    // global_id
+    header.get_next_i32().map_err(|_| INVALID_SHARD_STATE)?;
+    let shard = ShardIdent::construct_from(&mut header).map_err(|_| INVALID_SHARD_STATE)?;
+    let workchain_id = i8::try_from(shard.workchain_id()).map_err(|_| INVALID_SHARD_STATE)?;
+
+    // refs: out_msg_queue_info, accounts, other fields, custom
+    let accounts = root
+        .reference(1)
+        .and_then(ShardAccounts::construct_from_cell)
+        .map_err(|_| "Failed to read shard state accounts")?;
+
+    Ok((workchain_id, accounts))
+}
+
+const SHARD_STATE_TAG: u32 = 0x9023afe2;
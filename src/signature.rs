@@ -0,0 +1,95 @@
+use num_bigint::BigUint;
+
+use crate::utils::Result;
+
+/// Recoverable secp256k1 signature in the canonical form: low `s` and `v` in `{27, 28}`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Signature {
+    pub r: [u8; 32],
+    pub s: [u8; 32],
+    pub v: u8,
+    /// Original signature had `s` in the upper half of the curve order
+    pub high_s: bool,
+    /// Original signature differed from the canonical form
+    pub normalized: bool,
+}
+
+impl Signature {
+    /// Parses either 65 bytes `r || s || v` or 64 bytes EIP-2098 compact `r || yParityAndS`
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        let (r, s, parity, original_v) = match data.len() {
+            65 => {
+                let parity = match data[64] {
+                    v @ 0..=1 => v,
+                    v @ 27..=28 => v - 27,
+                    _ => return Err("Invalid signature recovery id"),
+                };
+                (&data[..32], data[32..64].to_vec(), parity, Some(data[64]))
+            }
+            64 => {
+                let mut s = data[32..64].to_vec();
+                let parity = s[0] >> 7;
+                s[0] &= 0x7f;
+                (&data[..32], s, parity, None)
+            }
+            _ => return Err("Invalid signature length"),
+        };
+
+        let order = BigUint::from_bytes_be(&SECP256K1_ORDER);
+        let r_value = BigUint::from_bytes_be(r);
+        let mut s_value = BigUint::from_bytes_be(&s);
+        let zero = BigUint::from(0u8);
+        if r_value == zero || r_value >= order || s_value == zero || s_value >= order {
+            return Err("Invalid signature");
+        }
+
+        let half_order = &order >> 1;
+        let high_s = s_value > half_order;
+        let mut parity = parity;
+        if high_s {
+            s_value = &order - s_value;
+            parity ^= 1;
+        }
+        let v = parity + 27;
+
+        Ok(Signature {
+            r: to_bytes32(&r_value),
+            s: to_bytes32(&s_value),
+            v,
+            high_s,
+            normalized: high_s || original_v != Some(v),
+        })
+    }
+
+    /// 65 bytes `r || s || v`
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut result = Vec::with_capacity(65);
+        result.extend_from_slice(&self.r);
+        result.extend_from_slice(&self.s);
+        result.push(self.v);
+        result
+    }
+
+    /// 64 bytes EIP-2098 `r || yParityAndS`
+    pub fn to_compact(&self) -> Vec<u8> {
+        let mut result = Vec::with_capacity(64);
+        result.extend_from_slice(&self.r);
+        result.extend_from_slice(&self.s);
+        if self.v == 28 {
+            result[32] |= 0x80;
+        }
+        result
+    }
+}
+
+fn to_bytes32(value: &BigUint) -> [u8; 32] {
+    let bytes = value.to_bytes_be();
+    let mut result = [0; 32];
+    result[32 - bytes.len()..].copy_from_slice(&bytes);
+    result
+}
+
+const SECP256K1_ORDER: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe, 0xba, 0xae, 0xdc,
+    0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c, 0xd0, 0x36, 0x41, 0x41,
+];
@@ -0,0 +1,98 @@
+use num_bigint::BigUint;
+use num_traits::ToPrimitive;
+use ton_abi::{Token, TokenValue};
+use ton_block::MsgAddressInt;
+use ton_types::{Cell, SliceData};
+
+use crate::eth;
+use crate::utils::Result;
+
+pub enum EventSummary {
+    TokenBurn(TokenBurnSummary),
+}
+
+pub struct TokenBurnSummary {
+    pub sender: MsgAddressInt,
+    pub amount: BigUint,
+    pub ethereum_address: ethabi::Address,
+}
+
+/// Returns `None` for unknown event kinds
+pub fn summarize_event(event_data: Cell, event_abi: &str) -> Result<Option<EventSummary>> {
+    let abi = eth::parse_event_abi(event_abi)?;
+    let tokens = eth::decode_event_data(event_data, event_abi)?;
+
+    Ok(match abi.name.as_str() {
+        "TokenBurn" => Some(EventSummary::TokenBurn(parse_token_burn(&tokens)?)),
+        _ => None,
+    })
+}
+
+fn parse_token_burn(tokens: &[Token]) -> Result<TokenBurnSummary> {
+    let workchain_id = match find_token(tokens, "wid")? {
+        TokenValue::Int(value) => value.number.to_i8().ok_or(INVALID_TOKEN_BURN)?,
+        _ => return Err(INVALID_TOKEN_BURN),
+    };
+
+    let address = match find_token(tokens, "addr")? {
+        TokenValue::Uint(value) => to_bytes(&value.number, 32)?,
+        _ => return Err(INVALID_TOKEN_BURN),
+    };
+    let sender = MsgAddressInt::with_standart(None, workchain_id, SliceData::from_raw(address, 256))
+        .map_err(|_| INVALID_TOKEN_BURN)?;
+
+    let amount = match find_token(tokens, "tokens")? {
+        TokenValue::Uint(value) => value.number.clone(),
+        _ => return Err(INVALID_TOKEN_BURN),
+    };
+
+    let ethereum_address = match find_token(tokens, "ethereum_address")? {
+        TokenValue::Uint(value) => ethabi::Address::from_slice(&to_bytes(&value.number, 20)?),
+        _ => return Err(INVALID_TOKEN_BURN),
+    };
+
+    Ok(TokenBurnSummary {
+        sender,
+        amount,
+        ethereum_address,
+    })
+}
+
+/// Formats integer amount as a decimal number with the specified number of decimals
+pub fn format_amount(amount: &BigUint, decimals: u8) -> String {
+    let amount = amount.to_string();
+    let decimals = decimals as usize;
+    if decimals == 0 {
+        return amount;
+    }
+
+    let amount = format!("{:0>width$}", amount, width = decimals + 1);
+    let (integer, fraction) = amount.split_at(amount.len() - decimals);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        integer.to_owned()
+    } else {
+        format!("{}.{}", integer, fraction)
+    }
+}
+
+fn find_token<'a>(tokens: &'a [Token], name: &str) -> Result<&'a TokenValue> {
+    tokens
+        .iter()
+        .find(|token| token.name == name)
+        .map(|token| &token.value)
+        .ok_or(INVALID_TOKEN_BURN)
+}
+
+fn to_bytes(number: &BigUint, len: usize) -> Result<Vec<u8>> {
+    let bytes = number.to_bytes_be();
+    if bytes.len() > len {
+        return Err(INVALID_TOKEN_BURN);
+    }
+
+    let mut result = vec![0; len - bytes.len()];
+    result.extend_from_slice(&bytes);
+    Ok(result)
+}
+
+const INVALID_TOKEN_BURN: &str = "Invalid TokenBurn event data";
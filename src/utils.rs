@@ -1,11 +1,14 @@
 use ton_block::{Account, AccountState, Deserializable};
-use ton_types::Cell;
+use ton_types::{Cell, UInt256};
 
 pub type Result<T, E = &'static str> = core::result::Result<T, E>;
 
 pub fn decode_account_state(account_state: &[u8]) -> Result<(Cell, Cell)> {
     let account = Account::construct_from_bytes(account_state).map_err(|_| "Failed to decode account state")?;
+    decode_account(&account)
+}
 
+pub fn decode_account(account: &Account) -> Result<(Cell, Cell)> {
     let state = match account.state() {
         Some(AccountState::AccountActive(state)) => state,
         _ => return Err("Account is not active"),
@@ -18,6 +21,90 @@ pub fn decode_account_state(account_state: &[u8]) -> Result<(Cell, Cell)> {
     }
 }
 
+pub fn decode_cell(boc: &str) -> Result<Cell> {
+    let boc = decode_boc(boc).map_err(|_| "Failed to parse Cell")?;
+    deserialize_cell(&boc)
+}
+
+pub fn deserialize_cell(boc: &[u8]) -> Result<Cell> {
+    ton_types::deserialize_tree_of_cells(&mut std::io::Cursor::new(boc)).map_err(|_| "Failed to parse Cell")
+}
+
+/// Decodes base64 or hex encoded BOC. Hex is detected by the BOC magic prefix,
+/// base64 encoded BOCs never start with it
+pub fn decode_boc(boc: &str) -> Result<Vec<u8>> {
+    if let Some(boc) = boc.strip_prefix("0x") {
+        return hex::decode(boc).map_err(|_| "Failed to decode BOC");
+    }
+
+    let is_hex = boc
+        .get(..8)
+        .map(|prefix| BOC_HEX_MAGICS.iter().any(|magic| prefix.eq_ignore_ascii_case(magic)))
+        .unwrap_or_default();
+
+    if is_hex {
+        hex::decode(boc).map_err(|_| "Failed to decode BOC")
+    } else {
+        base64::decode(boc).map_err(|_| "Failed to decode BOC")
+    }
+}
+
+const BOC_HEX_MAGICS: [&str; 3] = ["b5ee9c72", "68ff65f3", "acc3a728"];
+
+pub fn encode_cell(cell: &Cell) -> Result<String> {
+    serialize_cell(cell).map(|data| base64::encode(&data))
+}
+
+pub fn serialize_cell(cell: &Cell) -> Result<Vec<u8>> {
+    ton_types::serialize_toc(cell).map_err(|_| "Failed to serialize Cell")
+}
+
+pub fn parse_hash(hash: &str) -> Result<UInt256> {
+    match hex::decode(hash) {
+        Ok(hash) if hash.len() == 32 => Ok(hash.into()),
+        _ => Err("Invalid hash"),
+    }
+}
+
+pub fn parse_eth_address(address: &str) -> Result<ethabi::Address> {
+    parse_eth_address_with(address, false)
+}
+
+/// Parses hex address with optional `0x` prefix. Mixed case addresses must have a valid
+/// EIP-55 checksum, strict mode also rejects addresses without checksum
+pub fn parse_eth_address_with(address: &str, strict: bool) -> Result<ethabi::Address> {
+    let hex = address.strip_prefix("0x").unwrap_or(address);
+    let bytes = match hex::decode(hex) {
+        Ok(bytes) if bytes.len() == 20 => bytes,
+        _ => return Err("Invalid eth address"),
+    };
+    let address = ethabi::Address::from_slice(&bytes);
+
+    let has_checksum = hex.chars().any(|c| c.is_ascii_lowercase()) && hex.chars().any(|c| c.is_ascii_uppercase());
+    if (has_checksum || strict) && format_eth_address(&address)[2..] != *hex {
+        return Err("Invalid eth address checksum");
+    }
+
+    Ok(address)
+}
+
+/// Formats address with EIP-55 checksum
+pub fn format_eth_address(address: &ethabi::Address) -> String {
+    let address = hex::encode(address.as_bytes());
+    let hash = crate::eth::keccak256(address.as_bytes());
+
+    let mut result = String::from("0x");
+    for (i, c) in address.chars().enumerate() {
+        let nibble = (hash[i / 2] >> (if i % 2 == 0 { 4 } else { 0 })) & 0x0f;
+        if nibble >= 8 {
+            result.push(c.to_ascii_uppercase());
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
 pub fn set_panic_hook() {
     console_error_panic_hook::set_once();
 }
@@ -0,0 +1,57 @@
+use ton_block::{CommonMsgInfo, Deserializable, Message, Transaction};
+use ton_types::Cell;
+
+use crate::contract::TonEventInitData;
+use crate::eth;
+use crate::utils::Result;
+
+pub fn verify_event_transaction(init_data: &TonEventInitData, transaction: Cell, event_abi: &str) -> Result<()> {
+    if transaction.repr_hash() != init_data.event_transaction {
+        return Err("Event transaction hash mismatch");
+    }
+
+    let transaction = Transaction::construct_from_cell(transaction).map_err(|_| "Failed to decode transaction")?;
+    if transaction.logical_time() != init_data.event_transaction_lt {
+        return Err("Event transaction lt mismatch");
+    }
+
+    let message = find_event_message(&transaction, init_data.event_index)?;
+    let body = message.body().ok_or("Event message must have a body")?;
+
+    let abi = eth::parse_event_abi(event_abi)?;
+    let expected = abi
+        .decode_input(init_data.event_data.clone().into())
+        .map_err(|_| "Failed to decode TON event data")?;
+    let actual = abi
+        .decode_input(body)
+        .map_err(|_| "Failed to decode event message body")?;
+
+    if expected != actual {
+        return Err("Event data mismatch");
+    }
+
+    Ok(())
+}
+
+fn find_event_message(transaction: &Transaction, event_index: u32) -> Result<Message> {
+    let mut index = 0;
+    let mut result = None;
+
+    transaction
+        .iterate_out_msgs(|message| {
+            if !matches!(message.header(), CommonMsgInfo::ExtOutMsgInfo(_)) {
+                return Ok(true);
+            }
+
+            if index == event_index {
+                result = Some(message);
+                return Ok(false);
+            }
+
+            index += 1;
+            Ok(true)
+        })
+        .map_err(|_| "Failed to iterate transaction out messages")?;
+
+    result.ok_or("Event message not found")
+}
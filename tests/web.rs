@@ -1,7 +1,7 @@
 #![cfg(target_arch = "wasm32")]
 
+use wasm_bindgen::{JsCast, JsValue};
 use wasm_bindgen_test::*;
-wasm_bindgen_test_configure!(run_in_browser);
 
 use std::collections::HashMap;
 use std::io::Cursor;
@@ -10,10 +10,11 @@ use std::str::FromStr;
 use num_bigint::BigUint;
 use ton_abi::{Contract, Function, Param, ParamType, TokenValue};
 use ton_block::{
-    Account, AccountState, CommonMsgInfo, Deserializable, ExternalInboundMessageHeader, InRefValue, Message,
-    MsgAddressInt,
+    Account, AccountState, AccountStatus, CommonMsgInfo, Deserializable, ExtOutMessageHeader,
+    ExternalInboundMessageHeader, InRefValue, Message, MsgAddressInt, Serializable, Transaction,
 };
-use ton_types::Cell;
+use ton_explorer_event_details::{contract, verify};
+use ton_types::{BuilderData, Cell, SliceData};
 //
 // #[wasm_bindgen_test]
 // fn run_create_internal_body() {
@@ -87,8 +88,1442 @@ use ton_types::Cell;
 // }
 
 #[wasm_bindgen_test]
-fn decode_code() {
-    const CODE: &str = "te6ccgEBAQEASwAAkmfOizcAvCXsqEs1frjhFnN1Sz6BMwvjMPctvcwpcXHffR2tvlAAAAAAAAAAAA3gtrOnZAAA5Wb757UCqPE6x6L7bvYW/DguWqY=";
-    let toc = ton_types::deserialize_tree_of_cells(&mut std::io::Cursor::new(base64::decode(CODE).unwrap())).unwrap();
-    panic!("{:#.1024}", toc);
+fn inspect_token_burn_event_data() {
+    use ton_explorer_event_details::inspect;
+    use ton_explorer_event_details::summary::{self, EventSummary};
+
+    // TokenBurn event data: event id, wid, addr, 10^18 tokens and ethereum_address in a single cell
+    const EVENT_DATA: &str = "te6ccgEBAQEASwAAkmfOizcAvCXsqEs1frjhFnN1Sz6BMwvjMPctvcwpcXHffR2tvlAAAAAAAAAAAA3gtrOnZAAA5Wb757UCqPE6x6L7bvYW/DguWqY=";
+    let boc = base64::decode(EVENT_DATA).unwrap();
+
+    let result = inspect::inspect_boc(&boc, None).unwrap();
+    assert_eq!(result["total_cells"], 1);
+    assert_eq!(result["root"]["bit_length"], 584);
+    assert_eq!(result["root"]["refs"], serde_json::json!([]));
+    assert!(result["root"]["data"].as_str().unwrap().starts_with("67ce8b37"));
+
+    let event_data = ton_types::deserialize_tree_of_cells(&mut Cursor::new(&boc)).unwrap();
+    let token_burn = match summary::summarize_event(event_data, TOKEN_BURN_ABI).unwrap() {
+        Some(EventSummary::TokenBurn(token_burn)) => token_burn,
+        None => panic!("TokenBurn event is not recognized"),
+    };
+    assert_eq!(token_burn.amount, BigUint::from(1_000_000_000_000_000_000u64));
+    assert_eq!(summary::format_amount(&token_burn.amount, 18), "1");
+}
+
+const TOKEN_BURN_ABI: &str = r#"{"name":"TokenBurn","inputs":[{"name":"wid","type":"int8"},{"name":"addr","type":"uint256"},{"name":"tokens","type":"uint128"},{"name":"ethereum_address","type":"uint160"}],"outputs":[]}"#;
+
+// State of the TON event contract 0:4ae0e66dfcbb99370d711952a9e459e7894875f175f4e7203437429a8e5b3f45,
+// the same BOC as `TON_EVENT` in js/index.js. Last transaction lt 12541422000010, storage last paid
+// at 1617451057 (2021-04-03). The network and block it was taken from weren't recorded
+fn event_account() -> Account {
+    Account::construct_from_bytes(include_bytes!("ton_event_account.boc")).unwrap()
+}
+
+fn event_code_and_data() -> (Cell, Cell) {
+    match event_account().state() {
+        Some(AccountState::AccountActive(state)) => (state.code.clone().unwrap(), state.data.clone().unwrap()),
+        _ => panic!("Event account is not active"),
+    }
+}
+
+fn event_init_data() -> contract::TonEventInitData {
+    let (code, data) = event_code_and_data();
+    contract::get_details(code, data).unwrap().init_data
+}
+
+fn build_transaction(lt: u64, event_bodies: &[Cell]) -> Cell {
+    let account_id = SliceData::from_raw(vec![0x11; 32], 256);
+    let mut transaction = Transaction::with_address_and_status(account_id, AccountStatus::AccStateActive);
+    transaction.set_logical_time(lt);
+    for body in event_bodies {
+        let mut message = Message::with_ext_out_header(ExtOutMessageHeader::default());
+        message.set_body(body.clone().into());
+        transaction.add_out_message(&message).unwrap();
+    }
+    transaction.serialize().unwrap()
+}
+
+#[wasm_bindgen_test]
+fn verify_event_transaction_accepts_matching_transaction() {
+    let mut init_data = event_init_data();
+    let transaction = build_transaction(init_data.event_transaction_lt, &[init_data.event_data.clone()]);
+    init_data.event_transaction = transaction.repr_hash();
+
+    verify::verify_event_transaction(&init_data, transaction, TOKEN_BURN_ABI).unwrap();
+}
+
+#[wasm_bindgen_test]
+fn verify_event_transaction_rejects_other_transaction() {
+    let init_data = event_init_data();
+    let transaction = build_transaction(init_data.event_transaction_lt, &[init_data.event_data.clone()]);
+
+    assert_eq!(
+        verify::verify_event_transaction(&init_data, transaction, TOKEN_BURN_ABI),
+        Err("Event transaction hash mismatch")
+    );
+}
+
+#[wasm_bindgen_test]
+fn verify_event_transaction_rejects_lt_mismatch() {
+    let mut init_data = event_init_data();
+    let transaction = build_transaction(init_data.event_transaction_lt + 1, &[init_data.event_data.clone()]);
+    init_data.event_transaction = transaction.repr_hash();
+
+    assert_eq!(
+        verify::verify_event_transaction(&init_data, transaction, TOKEN_BURN_ABI),
+        Err("Event transaction lt mismatch")
+    );
+}
+
+#[wasm_bindgen_test]
+fn verify_event_transaction_checks_event_index() {
+    let mut init_data = event_init_data();
+    let transaction = build_transaction(
+        init_data.event_transaction_lt,
+        &[Cell::default(), init_data.event_data.clone()],
+    );
+    init_data.event_transaction = transaction.repr_hash();
+
+    init_data.event_index = 0;
+    assert_eq!(
+        verify::verify_event_transaction(&init_data, transaction.clone(), TOKEN_BURN_ABI),
+        Err("Failed to decode event message body")
+    );
+
+    init_data.event_index = 1;
+    verify::verify_event_transaction(&init_data, transaction.clone(), TOKEN_BURN_ABI).unwrap();
+
+    init_data.event_index = 2;
+    assert_eq!(
+        verify::verify_event_transaction(&init_data, transaction, TOKEN_BURN_ABI),
+        Err("Event message not found")
+    );
+}
+
+#[wasm_bindgen_test]
+fn boc_inputs_accept_prefixed_hex() {
+    let account_state = include_bytes!("ton_event_account.boc");
+    for encoded in [
+        hex::encode(&account_state[..]),
+        format!("0x{}", hex::encode(&account_state[..])),
+    ]
+    .iter()
+    {
+        let input = JsValue::from(encoded.as_str()).unchecked_into();
+        assert!(ton_explorer_event_details::get_details(&input, None).is_ok());
+    }
+}
+
+#[wasm_bindgen_test]
+fn encoded_cells_are_available_as_bytes() {
+    let address = "0xDceeAE4492732c04b5224841286bf7146aA299df";
+    let encoded = ton_explorer_event_details::encode_eth_address(address, None).unwrap();
+    let bytes = ton_explorer_event_details::encode_eth_address_bytes(address, None).unwrap();
+    assert_eq!(base64::decode(&encoded).unwrap(), bytes);
+}
+
+#[wasm_bindgen_test]
+fn event_address_is_derived_from_init_data() {
+    let account = event_account();
+    let address = account.get_addr().cloned().unwrap();
+    let (code, _) = event_code_and_data();
+
+    assert!(contract::verify_event_address(&account, code.clone()).unwrap());
+
+    let mut init_data = event_init_data();
+    assert_eq!(
+        contract::compute_event_address(code.clone(), &init_data, 0).unwrap(),
+        address
+    );
+
+    // changing only the rejections threshold must change the address
+    init_data.required_rejections += 1;
+    assert_ne!(contract::compute_event_address(code, &init_data, 0).unwrap(), address);
+}
+
+#[wasm_bindgen_test]
+fn code_hash_allowlist_checks_network_and_direction() {
+    use ton_explorer_event_details::authenticity::{self, Authenticity, CodeHashAllowlist, EventDirection};
+
+    let (code, data) = event_code_and_data();
+    let code_hash = code.repr_hash();
+    let allowlist = CodeHashAllowlist::from_json(&format!(
+        r#"[{{"network": "mainnet", "direction": "ton_to_eth", "code_hash": "{}"}}]"#,
+        code_hash.to_hex_string()
+    ))
+    .unwrap();
+
+    assert_eq!(
+        allowlist.check(&code_hash, "mainnet", EventDirection::TonToEth),
+        Authenticity::Trusted
+    );
+    assert_eq!(
+        allowlist.check(&code_hash, "testnet", EventDirection::TonToEth),
+        Authenticity::Mismatched
+    );
+    assert_eq!(
+        allowlist.check(&code_hash, "mainnet", EventDirection::EthToTon),
+        Authenticity::Mismatched
+    );
+    assert_eq!(
+        allowlist.check(&data.repr_hash(), "mainnet", EventDirection::TonToEth),
+        Authenticity::Unknown
+    );
+    assert_eq!(
+        CodeHashAllowlist::default().check(&code_hash, "mainnet", EventDirection::TonToEth),
+        Authenticity::Unknown
+    );
+
+    let checked =
+        authenticity::get_checked_details(code, data, &allowlist, "mainnet", EventDirection::TonToEth).unwrap();
+    assert_eq!(checked.authenticity, Authenticity::Trusted);
+    assert_eq!(checked.details.init_data.event_index, event_init_data().event_index);
+
+    assert!(
+        CodeHashAllowlist::from_json(r#"[{"network": "mainnet", "direction": "ton_to_eth", "code_hash": "00"}]"#)
+            .is_err()
+    );
+}
+
+// signed with the well known test key 0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318
+const RELAY_ETH_ADDRESS: &str = "2c7536e3605d9c16a7a3d7b1898e529396a65c23";
+const RELAY_PAYLOAD: &[u8] = b"ton event payload";
+const RELAY_SIGNATURE: &str = "ca00a27bc2ae1af923b146ac876be39ec352b58eee733321f9445b6c046054737fa8a9c1ff02601d332ba60c2beffedafdf8f8cc5b7485da5d63913ee31b7a431c";
+
+#[wasm_bindgen_test]
+fn recover_signer_returns_relay_address() {
+    use ton_explorer_event_details::eth;
+
+    let signature = hex::decode(RELAY_SIGNATURE).unwrap();
+    let expected = ethabi::Address::from_str(RELAY_ETH_ADDRESS).unwrap();
+
+    assert_eq!(eth::recover_signer(RELAY_PAYLOAD, &signature).unwrap(), expected);
+    assert_ne!(eth::recover_signer(b"other payload", &signature).unwrap(), expected);
+}
+
+// RELAY_SIGNATURE with `s` replaced by `n - s` and the recovery id flipped
+const RELAY_SIGNATURE_HIGH_S: &str = "ca00a27bc2ae1af923b146ac876be39ec352b58eee733321f9445b6c046054738057563e00fd9fe2ccd459f3d4100123bcb5e41a53d41a61626ecd4ded1ac6fe1b";
+const RELAY_SIGNATURE_COMPACT: &str = "ca00a27bc2ae1af923b146ac876be39ec352b58eee733321f9445b6c04605473ffa8a9c1ff02601d332ba60c2beffedafdf8f8cc5b7485da5d63913ee31b7a43";
+
+#[wasm_bindgen_test]
+fn signature_high_s_is_normalized() {
+    use ton_explorer_event_details::{eth, signature::Signature};
+
+    let canonical = Signature::parse(&hex::decode(RELAY_SIGNATURE).unwrap()).unwrap();
+    assert!(!canonical.high_s);
+    assert!(!canonical.normalized);
+    assert_eq!(canonical.v, 28);
+
+    let signature = Signature::parse(&hex::decode(RELAY_SIGNATURE_HIGH_S).unwrap()).unwrap();
+    assert!(signature.high_s);
+    assert!(signature.normalized);
+    assert_eq!(signature.to_bytes(), hex::decode(RELAY_SIGNATURE).unwrap());
+
+    let expected = ethabi::Address::from_str(RELAY_ETH_ADDRESS).unwrap();
+    assert_eq!(
+        eth::recover_signer(RELAY_PAYLOAD, &signature.to_bytes()).unwrap(),
+        expected
+    );
+}
+
+#[wasm_bindgen_test]
+fn signature_forms() {
+    use ton_explorer_event_details::signature::Signature;
+
+    let canonical = Signature::parse(&hex::decode(RELAY_SIGNATURE).unwrap()).unwrap();
+
+    // recovery id 0/1 is accepted and converted to 27/28
+    let mut raw = hex::decode(RELAY_SIGNATURE).unwrap();
+    raw[64] = 1;
+    let signature = Signature::parse(&raw).unwrap();
+    assert_eq!(signature.to_bytes(), canonical.to_bytes());
+    assert!(signature.normalized);
+    assert!(!signature.high_s);
+
+    let compact = Signature::parse(&hex::decode(RELAY_SIGNATURE_COMPACT).unwrap()).unwrap();
+    assert_eq!(compact.to_bytes(), canonical.to_bytes());
+    assert_eq!(hex::encode(canonical.to_compact()), RELAY_SIGNATURE_COMPACT);
+
+    raw[64] = 29;
+    assert_eq!(Signature::parse(&raw), Err("Invalid signature recovery id"));
+    assert_eq!(Signature::parse(&raw[..63]), Err("Invalid signature length"));
+    assert_eq!(Signature::parse(&[0; 65]), Err("Invalid signature"));
+}
+
+#[wasm_bindgen_test]
+fn signature_keeps_invalid_hex() {
+    let signature = ton_explorer_event_details::Signature::new("0xzz");
+    assert!(signature.malformed());
+    assert_eq!(signature.raw(), "0xzz");
+    assert_eq!(signature.error().as_deref(), Some("Invalid signature hex"));
+
+    let signature = ton_explorer_event_details::Signature::new(&format!("0x{}", RELAY_SIGNATURE_HIGH_S));
+    assert!(!signature.malformed());
+    assert_eq!(signature.bytes().as_deref(), Some(RELAY_SIGNATURE));
+}
+
+#[wasm_bindgen_test]
+fn analyze_quorum_flags_mismatched_signatures() {
+    use ton_explorer_event_details::quorum::{analyze_quorum, Relay};
+
+    let (code, data) = event_code_and_data();
+    let mut details = contract::get_details(code, data).unwrap();
+
+    let relay = |n: u8| MsgAddressInt::from_str(&format!("0:{}", hex::encode([n; 32]))).unwrap();
+    let relays = vec![
+        Relay {
+            ton_address: relay(1),
+            eth_address: ethabi::Address::from_str(RELAY_ETH_ADDRESS).unwrap(),
+        },
+        Relay {
+            ton_address: relay(2),
+            eth_address: ethabi::Address::from_str("8ba53d12d8566a3d5870373e9db6dd6a080d58a0").unwrap(),
+        },
+        Relay {
+            ton_address: relay(3),
+            eth_address: ethabi::Address::from_str(RELAY_ETH_ADDRESS).unwrap(),
+        },
+    ];
+
+    // the second relay submitted a signature of another key, the third one has no signature at all
+    let signature = hex::decode(RELAY_SIGNATURE).unwrap();
+    details.confirms = vec![relay(1), relay(2), relay(3)];
+    details.rejections = Vec::new();
+    details.signatures = vec![signature.clone(), signature];
+
+    let analysis = analyze_quorum(&details, &relays, RELAY_PAYLOAD);
+    assert_eq!(analysis.invalid_signatures, vec![relay(2), relay(3)]);
+    assert!(analysis.not_voted.is_empty());
+    assert!(analysis.unknown_relays.is_empty());
+}
+
+#[wasm_bindgen_test]
+fn converted_init_data_keeps_rejections_threshold() {
+    let account_state: ton_explorer_event_details::BocInput =
+        js_sys::Uint8Array::from(&include_bytes!("ton_event_account.boc")[..]).unchecked_into();
+    let details = ton_explorer_event_details::get_details(&account_state, None).unwrap();
+    let required_confirmations = details.init_data().required_confirmations();
+
+    let json: JsValue = details.to_json().unwrap().into();
+    let json_init_data = js_sys::Reflect::get(&json, &JsValue::from("initData")).unwrap();
+    let required_rejections = JsValue::from(required_confirmations + 1);
+    js_sys::Reflect::set(
+        &json_init_data,
+        &JsValue::from("requiredRejections"),
+        &required_rejections,
+    )
+    .unwrap();
+    let json = js_sys::JSON::stringify(&json).unwrap().as_string().unwrap();
+
+    // `withAddressFormat` converts the init data through the contract representation and back
+    let details = ton_explorer_event_details::TonEventDetails::from_json(&json)
+        .unwrap()
+        .with_address_format(&ton_explorer_event_details::AddressFormat::raw())
+        .unwrap();
+    let init_data = details.init_data();
+    assert_eq!(init_data.required_confirmations(), required_confirmations);
+    assert_eq!(init_data.required_rejections(), required_confirmations + 1);
+}
+
+fn fixture_details_json() -> serde_json::Value {
+    let account_state: ton_explorer_event_details::BocInput =
+        js_sys::Uint8Array::from(&include_bytes!("ton_event_account.boc")[..]).unchecked_into();
+    let details = ton_explorer_event_details::get_details(&account_state, None).unwrap();
+    let json: JsValue = details.to_json().unwrap().into();
+    serde_json::from_str(&js_sys::JSON::stringify(&json).unwrap().as_string().unwrap()).unwrap()
+}
+
+#[wasm_bindgen_test]
+fn init_data_constructor_validates_values() {
+    use ton_explorer_event_details::{BocInput, TonEventInitData};
+
+    let json = fixture_details_json();
+    let init_data = &json["initData"];
+    let string = |key: &str| init_data[key].as_str().unwrap().to_owned();
+    let boc = |value: &str| -> BocInput { JsValue::from(value).unchecked_into() };
+
+    let new = |event_transaction: String, lt: JsValue, event_data: &str| {
+        TonEventInitData::new(
+            event_transaction,
+            lt,
+            init_data["eventTimestamp"].as_u64().unwrap() as u32,
+            init_data["eventIndex"].as_u64().unwrap() as u32,
+            &boc(event_data),
+            string("tonEventConfiguration"),
+            2,
+            2,
+            &boc(&string("configurationMeta")),
+        )
+    };
+    let hash = string("eventTransaction");
+    let lt = string("eventTransactionLt");
+    let event_data = string("eventData");
+
+    let created = new(hash.clone(), JsValue::from(lt.as_str()), &event_data).unwrap();
+    assert_eq!(created.event_transaction(), hash);
+    assert_eq!(created.event_transaction_lt(), lt);
+    let lt_number = JsValue::from(lt.parse::<u64>().unwrap() as f64);
+    assert!(new(hash.clone(), lt_number, &event_data).is_ok());
+
+    assert!(new(hash[2..].to_owned(), JsValue::from(lt.as_str()), &event_data).is_err());
+    assert!(new(format!("{}00", hash), JsValue::from(lt.as_str()), &event_data).is_err());
+    assert!(new(hash.clone(), JsValue::from("12a"), &event_data).is_err());
+    assert!(new(hash.clone(), JsValue::from(-1.0), &event_data).is_err());
+    assert!(new(hash.clone(), JsValue::from(9007199254740993.0), &event_data).is_err());
+    assert!(new(hash, JsValue::from(lt.as_str()), "not a boc").is_err());
+}
+
+#[wasm_bindgen_test]
+fn init_data_json_is_validated() {
+    use ton_explorer_event_details::TonEventInitData;
+
+    let json = fixture_details_json();
+    let from_json = |key: &str, value: serde_json::Value| {
+        let mut init_data = json["initData"].clone();
+        init_data[key] = value;
+        TonEventInitData::from_json(&init_data.to_string())
+    };
+    let hash = json["initData"]["eventTransaction"].as_str().unwrap().to_owned();
+
+    assert!(from_json("eventIndex", json["initData"]["eventIndex"].clone()).is_ok());
+    assert!(from_json("eventTransactionLt", serde_json::json!(12541422000010u64)).is_ok());
+
+    assert!(from_json("eventTransaction", serde_json::json!(&hash[2..])).is_err());
+    assert!(from_json("eventTransaction", serde_json::json!(format!("{}00", hash))).is_err());
+    assert!(from_json("eventTransactionLt", serde_json::json!("12a")).is_err());
+    assert!(from_json("eventTransactionLt", serde_json::json!(9007199254740993u64)).is_err());
+    assert!(from_json("eventData", serde_json::json!("not a boc")).is_err());
+}
+
+#[wasm_bindgen_test]
+fn details_constructor_and_json_are_validated() {
+    use ton_explorer_event_details::{EventStatus, TonEventDetails, TonEventInitData};
+
+    let json = fixture_details_json();
+    let init_data = TonEventInitData::from_json(&json["initData"].to_string()).unwrap();
+    let strings = |values: &serde_json::Value| {
+        values
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|value| JsValue::from(value.as_str().unwrap()))
+            .collect::<js_sys::Array>()
+    };
+    let new = |confirmations: js_sys::Array, signatures: js_sys::Array| {
+        TonEventDetails::new(
+            &init_data,
+            EventStatus::InProcess,
+            confirmations.unchecked_into(),
+            js_sys::Array::new().unchecked_into(),
+            signatures.unchecked_into(),
+        )
+    };
+
+    assert!(new(strings(&json["confirmations"]), strings(&json["signatures"])).is_ok());
+    assert!(new(js_sys::Array::of1(&JsValue::from("invalid")), js_sys::Array::new()).is_err());
+    assert!(new(js_sys::Array::of1(&JsValue::from(1)), js_sys::Array::new()).is_err());
+    assert!(new(js_sys::Array::new(), js_sys::Array::of1(&JsValue::from(1))).is_err());
+
+    let from_json = |path: &[&str], value: serde_json::Value| {
+        let mut details = json.clone();
+        let (key, parents) = path.split_last().unwrap();
+        let mut target = &mut details;
+        for parent in parents {
+            target = &mut target[*parent];
+        }
+        target[*key] = value;
+        TonEventDetails::from_json(&details.to_string())
+    };
+    let hash = json["initData"]["eventTransaction"].as_str().unwrap().to_owned();
+
+    assert!(from_json(&["status"], json["status"].clone()).is_ok());
+    assert!(from_json(&["initData", "eventTransaction"], serde_json::json!(&hash[2..])).is_err());
+    assert!(from_json(
+        &["initData", "eventTransaction"],
+        serde_json::json!(format!("{}00", hash))
+    )
+    .is_err());
+    assert!(from_json(&["initData", "eventTransactionLt"], serde_json::json!("12a")).is_err());
+    assert!(from_json(
+        &["initData", "eventTransactionLt"],
+        serde_json::json!(9007199254740993u64)
+    )
+    .is_err());
+    assert!(from_json(&["initData", "eventData"], serde_json::json!("not a boc")).is_err());
+    assert!(from_json(&["confirmations"], serde_json::json!(["invalid"])).is_err());
+    assert!(from_json(&["status"], serde_json::json!("Unknown")).is_err());
+}
+
+#[wasm_bindgen_test]
+fn non_standard_addresses_are_kept_raw() {
+    let account_state: ton_explorer_event_details::BocInput =
+        js_sys::Uint8Array::from(&include_bytes!("ton_event_account.boc")[..]).unchecked_into();
+    let details = ton_explorer_event_details::get_details(&account_state, None).unwrap();
+
+    // workchain id doesn't fit into int8, so the address is parsed as `addr_var`
+    let var_address = format!("1000:{}", "11".repeat(32));
+    assert!(matches!(
+        MsgAddressInt::from_str(&var_address).unwrap(),
+        MsgAddressInt::AddrVar(_)
+    ));
+
+    let json: JsValue = details.to_json().unwrap().into();
+    let confirmations = js_sys::Array::of1(&JsValue::from(var_address.as_str()));
+    js_sys::Reflect::set(&json, &JsValue::from("confirmations"), &confirmations).unwrap();
+    let json = js_sys::JSON::stringify(&json).unwrap().as_string().unwrap();
+
+    let details = ton_explorer_event_details::TonEventDetails::from_json(&json)
+        .unwrap()
+        .with_address_format(&ton_explorer_event_details::AddressFormat::bounceable(true, false))
+        .unwrap();
+    let confirmations: js_sys::Array = details.confirmations().unchecked_into();
+    assert_eq!(confirmations.length(), 1);
+    assert_eq!(confirmations.get(0).as_string().unwrap(), var_address);
+    assert!(!details.init_data().ton_event_configuration().contains(':'));
+}
+
+#[wasm_bindgen_test]
+fn configuration_registry_checks_chain_id() {
+    use ton_explorer_event_details::registry::ConfigurationRegistry;
+
+    let configuration = MsgAddressInt::from_str(&format!("0:{}", "11".repeat(32))).unwrap();
+    let mut registry = ConfigurationRegistry::from_toml(&format!(
+        r#"
+["{}"]
+event_abi = '{}'
+proxy_address = "0xDceeAE4492732c04b5224841286bf7146aA299df"
+chain_id = 1
+"#,
+        configuration, TOKEN_BURN_ABI
+    ))
+    .unwrap();
+
+    assert_eq!(registry.get(&configuration).unwrap().event_abi, TOKEN_BURN_ABI);
+
+    registry.set_chain_id(Some(1));
+    assert!(registry.get(&configuration).is_ok());
+
+    registry.set_chain_id(Some(56));
+    assert_eq!(
+        registry.get(&configuration).unwrap_err(),
+        "Event configuration belongs to another chain"
+    );
+}
+
+#[wasm_bindgen_test]
+fn map_eth_to_ton_checks_int_size() {
+    use ton_explorer_event_details::eth;
+
+    let param = |size| Param {
+        name: "value".to_owned(),
+        kind: ParamType::Int(size),
+    };
+    let int = |value: u64| ethabi::Token::Int(ethabi::Int::from(value));
+    // -1 in two's complement
+    let minus_one = ethabi::Token::Int(ethabi::Int::max_value());
+
+    assert_eq!(
+        eth::map_eth_to_ton(vec![int(0)], &[param(0)]).unwrap_err(),
+        "Invalid int size"
+    );
+
+    assert!(eth::map_eth_to_ton(vec![minus_one], &[param(8)]).is_ok());
+    assert!(eth::map_eth_to_ton(vec![int(127)], &[param(8)]).is_ok());
+    assert_eq!(
+        eth::map_eth_to_ton(vec![int(128)], &[param(8)]).unwrap_err(),
+        "Int value out of range"
+    );
+}
+
+#[wasm_bindgen_test]
+fn eth_payload_checks_configuration_address() {
+    use ton_block::AnycastInfo;
+    use ton_explorer_event_details::eth::{self, EthPayload};
+
+    let encode = |event_configuration: MsgAddressInt| {
+        let init_data = event_init_data();
+        let payload = EthPayload {
+            event_transaction: init_data.event_transaction,
+            event_transaction_lt: init_data.event_transaction_lt,
+            event_timestamp: init_data.event_timestamp,
+            event_index: init_data.event_index,
+            event_data: init_data.event_data,
+            event_configuration,
+            required_confirmations: 2,
+            required_rejections: 2,
+            proxy: ethabi::Address::from_str(RELAY_ETH_ADDRESS).unwrap(),
+        };
+        eth::encode_eth_payload(payload, TOKEN_BURN_ABI)
+    };
+    let address = || SliceData::from_raw(vec![0x11; 32], 256);
+
+    assert!(encode(MsgAddressInt::with_standart(None, -1, address()).unwrap()).is_ok());
+    assert!(encode(MsgAddressInt::with_variant(None, 127, address()).unwrap()).is_ok());
+
+    let anycast = AnycastInfo::with_rewrite_pfx(SliceData::new(vec![0xc0])).unwrap();
+    assert_eq!(
+        encode(MsgAddressInt::with_standart(Some(anycast.clone()), 0, address()).unwrap()),
+        Err("Anycast configuration addresses are not supported")
+    );
+    assert_eq!(
+        encode(MsgAddressInt::with_variant(Some(anycast), 0, address()).unwrap()),
+        Err("Anycast configuration addresses are not supported")
+    );
+    assert_eq!(
+        encode(MsgAddressInt::with_variant(None, 128, address()).unwrap()),
+        Err("Workchain id is out of range")
+    );
+    assert_eq!(
+        encode(MsgAddressInt::with_variant(None, -129, address()).unwrap()),
+        Err("Workchain id is out of range")
+    );
+    assert_eq!(
+        encode(MsgAddressInt::with_variant(None, 0, SliceData::from_raw(vec![0x11; 32], 255)).unwrap()),
+        Err("Configuration address must be 256 bits long")
+    );
+}
+
+#[wasm_bindgen_test]
+fn event_data_from_log_is_decodable() {
+    use ton_explorer_event_details::eth;
+
+    let eth_event_abi = r#"{"name":"TokenLock","inputs":[{"name":"amount","type":"uint128","indexed":false},{"name":"wid","type":"int8","indexed":false},{"name":"addr","type":"uint256","indexed":false}],"anonymous":false}"#;
+    let ton_event_abi = r#"{"name":"TokenLock","inputs":[{"name":"amount","type":"uint128"},{"name":"wid","type":"int8"},{"name":"addr","type":"uint256"}],"outputs":[]}"#;
+
+    let signature = serde_json::from_str::<ethabi::Event>(eth_event_abi)
+        .unwrap()
+        .signature();
+    let data = ethabi::encode(&[
+        ethabi::Token::Uint(ethabi::Uint::from(1_000u64)),
+        ethabi::Token::Int(ethabi::Int::from(0u64)),
+        ethabi::Token::Uint(ethabi::Uint::from(42u64)),
+    ]);
+
+    let event_data = eth::encode_event_data_from_log(vec![signature], data, eth_event_abi, ton_event_abi).unwrap();
+    let tokens = eth::decode_event_data(event_data, ton_event_abi).unwrap();
+    assert_eq!(tokens.len(), 3);
+    assert_eq!(tokens[0].value, uint_token(1_000, 128));
+    assert_eq!(tokens[1].value, TokenValue::Int(ton_abi::Int::new(0, 8)));
+    assert_eq!(tokens[2].value, uint_token(42, 256));
+}
+
+fn code_cell(code: &str, references: Vec<Cell>) -> Cell {
+    let data = hex::decode(code).unwrap();
+    let mut builder = ton_types::BuilderData::new();
+    builder.append_raw(&data, data.len() * 8).unwrap();
+    for reference in references {
+        builder.checked_append_reference(reference).unwrap();
+    }
+    builder.into_cell().unwrap()
+}
+
+#[wasm_bindgen_test]
+fn disassemble_simple_opcodes() {
+    use ton_explorer_event_details::disasm;
+
+    let functions = HashMap::new();
+
+    // SETCP0, ACCEPT, tiny/int8/int16 PUSHINT, ADD, RET
+    let code = code_cell("ff00f800717f80fb810100a0db30", Vec::new());
+    assert_eq!(
+        disasm::disassemble(&code, &functions).unwrap(),
+        "SETCP0\nACCEPT\nPUSHINT 1\nPUSHINT -1\nPUSHINT -5\nPUSHINT 256\nADD\nRET\n"
+    );
+
+    let code = code_cell("91a0f224", Vec::new());
+    assert_eq!(
+        disasm::disassemble(&code, &functions).unwrap(),
+        "PUSHCONT {\n  ADD\n}\nTHROW 36\n"
+    );
+
+    // truncated PUSHINT is kept as raw data
+    let code = code_cell("a08101", Vec::new());
+    assert_eq!(disasm::disassemble(&code, &functions).unwrap(), "ADD\n.blob x{8101}\n");
+}
+
+#[wasm_bindgen_test]
+fn disassemble_function_selector() {
+    use ton_explorer_event_details::disasm;
+    use ton_types::{HashmapE, HashmapType, IBitstring};
+
+    let mut selector = HashmapE::with_bit_len(32);
+    let mut key = ton_types::BuilderData::new();
+    key.append_u32(0x12345678).unwrap();
+    let body = code_cell("a0db30", Vec::new());
+    selector
+        .set_builder(key.into_cell().unwrap().into(), &ton_types::BuilderData::from(&body))
+        .unwrap();
+
+    // DICTPUSHCONST 32
+    let code = code_cell("f4a420", vec![selector.data().cloned().unwrap()]);
+    assert_eq!(disasm::find_function_ids(&code).unwrap(), vec![0x12345678]);
+
+    let mut functions = HashMap::new();
+    functions.insert(0x12345678, "transfer".to_owned());
+    assert_eq!(
+        disasm::disassemble(&code, &functions).unwrap(),
+        "DICTPUSHCONST 32 {\n  305419896 => { ;; transfer\n    ADD\n    RET\n  }\n}\n"
+    );
+}
+
+#[wasm_bindgen_test]
+fn built_in_get_details_is_only_used_for_labels() {
+    use ton_explorer_event_details::{disasm, functions};
+    use ton_types::{HashmapE, HashmapType, IBitstring};
+
+    let get_details = contract::abi_get_details().get_input_id();
+    assert_eq!(
+        disasm::known_functions(&[]).get(&get_details).map(String::as_str),
+        Some("getDetails")
+    );
+    assert!(disasm::abi_functions(&[]).is_empty());
+
+    // the test ABI has an older `getDetails` signature, so its id differs from the built-in one
+    let abi = Contract::load(Cursor::new(include_str!("TonEvent.abi.json"))).unwrap();
+    let abi_get_details = abi.function("getDetails").unwrap().get_input_id();
+    assert_ne!(abi_get_details, get_details);
+
+    let mut selector = HashmapE::with_bit_len(32);
+    for id in [get_details, abi_get_details].iter() {
+        let mut key = ton_types::BuilderData::new();
+        key.append_u32(*id).unwrap();
+        let body = code_cell("db30", Vec::new());
+        selector
+            .set_builder(key.into_cell().unwrap().into(), &ton_types::BuilderData::from(&body))
+            .unwrap();
+    }
+    let code = code_cell("f4a420", vec![selector.data().cloned().unwrap()]);
+
+    let disassembled = disasm::disassemble(&code, &disasm::known_functions(std::slice::from_ref(&abi))).unwrap();
+    assert!(disassembled.contains(&format!("{} => {{ ;; getDetails", get_details)));
+    assert!(disassembled.contains(&format!("{} => {{ ;; getDetails", abi_get_details)));
+
+    let listed = functions::list_code_functions(&code, &[abi]).unwrap();
+    assert!(listed.found.iter().any(|function| function.id == abi_get_details));
+    assert!(!listed.found.iter().any(|function| function.id == get_details));
+    assert!(!listed.missing.iter().any(|function| function.id == get_details));
+    assert_eq!(listed.unknown, vec![get_details]);
+}
+
+const RAW_ADDRESS: &str = "0:83dfd552e63729b472fcbcc8c45ebcc6691702558b68ec7527e1ba403a0f31a8";
+
+#[wasm_bindgen_test]
+fn user_friendly_address_vectors() {
+    use ton_explorer_event_details::address::{self, AddressFormat};
+
+    let user_friendly = |bounceable, testnet| AddressFormat::UserFriendly {
+        bounceable,
+        url_safe: true,
+        testnet,
+    };
+    let raw = MsgAddressInt::from_str(RAW_ADDRESS).unwrap();
+
+    for (format, expected) in [
+        (
+            user_friendly(true, false),
+            "EQCD39VS5jcptHL8vMjEXrzGaRcCVYto7HUn4bpAOg8xqB2N",
+        ),
+        (
+            user_friendly(false, false),
+            "UQCD39VS5jcptHL8vMjEXrzGaRcCVYto7HUn4bpAOg8xqEBI",
+        ),
+        (
+            user_friendly(true, true),
+            "kQCD39VS5jcptHL8vMjEXrzGaRcCVYto7HUn4bpAOg8xqKYH",
+        ),
+        (
+            user_friendly(false, true),
+            "0QCD39VS5jcptHL8vMjEXrzGaRcCVYto7HUn4bpAOg8xqPvC",
+        ),
+    ]
+    .iter()
+    {
+        assert_eq!(address::format_address(&raw, *format).unwrap(), *expected);
+        assert_eq!(
+            address::decode_user_friendly(expected, true).unwrap(),
+            (raw.clone(), *format)
+        );
+    }
+
+    let masterchain = MsgAddressInt::from_str(&format!("-1:{}", "00".repeat(32))).unwrap();
+    assert_eq!(
+        address::format_address(&masterchain, user_friendly(true, false)).unwrap(),
+        "Ef8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAADAU"
+    );
+
+    assert_eq!(
+        address::decode_user_friendly("EQCD39VS5jcptHL8vMjEXrzGaRcCVYto7HUn4bpAOg8xqB2M", true),
+        Err("Invalid user friendly address checksum")
+    );
+}
+
+#[wasm_bindgen_test]
+fn user_friendly_address_alphabet_is_explicit() {
+    use ton_explorer_event_details::address;
+
+    const URL_SAFE: &str = "EQAKCgoKCgoKCgoKCgoKCgoKCgoKCgoKCgoKCgoKCgoKCt_a";
+    const STANDARD: &str = "EQAKCgoKCgoKCgoKCgoKCgoKCgoKCgoKCgoKCgoKCgoKCt/a";
+    let raw = MsgAddressInt::from_str(&format!("0:{}", "0a".repeat(32))).unwrap();
+
+    assert_eq!(address::parse_address(URL_SAFE, Some(true)).unwrap(), raw);
+    assert_eq!(address::parse_address(STANDARD, Some(false)).unwrap(), raw);
+    assert!(address::parse_address(URL_SAFE, Some(false)).is_err());
+    assert!(address::parse_address(STANDARD, Some(true)).is_err());
+
+    assert_eq!(address::parse_address(URL_SAFE, None).unwrap(), raw);
+    assert_eq!(address::parse_address(STANDARD, None).unwrap(), raw);
+    assert_eq!(
+        address::parse_address(RAW_ADDRESS, Some(true)).unwrap().to_string(),
+        RAW_ADDRESS
+    );
+}
+
+#[wasm_bindgen_test]
+fn eth_address_checksum_vectors() {
+    use ton_explorer_event_details::format_eth_address;
+
+    // test vectors from EIP-55
+    for address in [
+        "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+        "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+        "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+        "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+    ]
+    .iter()
+    {
+        let lowercase = address.to_ascii_lowercase();
+        assert_eq!(format_eth_address(&lowercase, None).unwrap(), *address);
+        assert_eq!(format_eth_address(&lowercase[2..], None).unwrap(), *address);
+        assert_eq!(format_eth_address(address, Some(true)).unwrap(), *address);
+        assert!(format_eth_address(&lowercase, Some(true)).is_err());
+    }
+
+    // case of a single letter changed
+    assert!(format_eth_address("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD", None).is_err());
+}
+
+#[wasm_bindgen_test]
+fn relay_eth_addresses_can_require_checksum() {
+    use ton_explorer_event_details::{analyze_quorum, RelaySet};
+
+    let checksummed = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+    let lowercase = checksummed.to_ascii_lowercase();
+    let ton_address = format!("0:{}", "11".repeat(32));
+
+    let mut relays = RelaySet::new();
+    assert!(relays.add(&ton_address, &lowercase, None).is_ok());
+    assert!(relays.add(&ton_address, checksummed, Some(true)).is_ok());
+    assert!(relays.add(&ton_address, &lowercase, Some(true)).is_err());
+
+    let account_state: ton_explorer_event_details::BocInput =
+        js_sys::Uint8Array::from(&include_bytes!("ton_event_account.boc")[..]).unchecked_into();
+    let event = ton_explorer_event_details::get_details(&account_state, None).unwrap();
+    assert!(analyze_quorum(&event, &relays, "", &lowercase, Some(true), None).is_err());
+}
+
+#[wasm_bindgen_test]
+fn compute_event_address_checks_workchain_range() {
+    let (code, _) = event_code_and_data();
+    let init_data = event_init_data();
+
+    let masterchain = contract::compute_event_address(code.clone(), &init_data, -1).unwrap();
+    assert_eq!(masterchain.workchain_id(), -1);
+
+    for workchain_id in [128, -129, 256].iter() {
+        assert_eq!(
+            contract::compute_event_address(code.clone(), &init_data, *workchain_id),
+            Err("Workchain id is out of range")
+        );
+    }
+}
+
+fn shard_account(account_root: Cell) -> ton_block::ShardAccount {
+    // account:^Account last_trans_hash:bits256 last_trans_lt:uint64
+    let mut builder = ton_types::BuilderData::new();
+    builder.checked_append_reference(account_root).unwrap();
+    builder.append_raw(&[0; 40], 320).unwrap();
+    ton_block::ShardAccount::construct_from_cell(builder.into_cell().unwrap()).unwrap()
+}
+
+#[wasm_bindgen_test]
+fn iterate_event_accounts_reports_broken_accounts() {
+    use ton_block::{DepthBalanceInfo, HashmapAugType, ShardAccounts, ShardIdent, ShardStateUnsplit};
+    use ton_explorer_event_details::shard;
+    use ton_types::UInt256;
+
+    let account = event_account();
+    let address = account.get_addr().cloned().unwrap();
+    let event_id = UInt256::from(address.address().get_bytestring(0));
+
+    // account$1 without the rest of the fields
+    let mut broken = ton_types::BuilderData::new();
+    broken.append_raw(&[0x80], 1).unwrap();
+    let broken_id = UInt256::from(vec![0x22; 32]);
+
+    let mut accounts = ShardAccounts::default();
+    accounts
+        .set(
+            &event_id,
+            &shard_account(account.serialize().unwrap()),
+            &DepthBalanceInfo::default(),
+        )
+        .unwrap();
+    accounts
+        .set(
+            &broken_id,
+            &shard_account(broken.into_cell().unwrap()),
+            &DepthBalanceInfo::default(),
+        )
+        .unwrap();
+
+    let mut state = ShardStateUnsplit::with_ident(ShardIdent::full(0));
+    state.write_accounts(&accounts).unwrap();
+    let state = ton_types::serialize_toc(&state.serialize().unwrap()).unwrap();
+
+    let iterate = |code_hashes: &[UInt256]| {
+        let mut result = Vec::new();
+        shard::iterate_event_accounts(&state, code_hashes, |address, details| {
+            result.push((
+                address.to_string(),
+                details.map(|details| details.init_data.event_index),
+            ));
+            true
+        })
+        .unwrap();
+        result.sort();
+        result
+    };
+
+    let (code, _) = event_code_and_data();
+    let broken_address = format!("0:{}", "22".repeat(32));
+    let mut expected = vec![
+        (broken_address.clone(), Err("Failed to decode account")),
+        (address.to_string(), Ok(event_init_data().event_index)),
+    ];
+    expected.sort();
+    assert_eq!(iterate(&[code.repr_hash()]), expected);
+    assert_eq!(iterate(&[]), vec![(broken_address, Err("Failed to decode account"))]);
+
+    assert_eq!(
+        shard::iterate_event_accounts(&ton_types::serialize_toc(&code).unwrap(), &[], |_, _| true),
+        Err("Failed to decode shard state")
+    );
+}
+
+fn vote_transaction(event_address: &MsgAddressInt, lt: u64, function: &str, relay: &MsgAddressInt) -> Transaction {
+    let abi = Contract::load(Cursor::new(include_str!("TonEvent.abi.json"))).unwrap();
+    let function = abi.function(function).unwrap();
+
+    let relay_token = match relay.clone() {
+        MsgAddressInt::AddrStd(address) => ton_block::MsgAddress::AddrStd(address),
+        MsgAddressInt::AddrVar(address) => ton_block::MsgAddress::AddrVar(address),
+    };
+    let mut tokens = vec![ton_abi::Token {
+        name: "relay".to_owned(),
+        value: TokenValue::Address(relay_token),
+    }];
+    if function.inputs.len() > 1 {
+        tokens.push(ton_abi::Token {
+            name: "eventDataSignature".to_owned(),
+            value: TokenValue::Bytes(hex::decode(RELAY_SIGNATURE).unwrap()),
+        });
+    }
+    let body = function.encode_input(&HashMap::new(), &tokens, true, None).unwrap();
+
+    let mut transaction = Transaction::with_address_and_status(event_address.address(), AccountStatus::AccStateActive);
+    transaction.set_logical_time(lt);
+    let message = {
+        let header = ton_block::InternalMessageHeader::with_addresses(
+            relay.clone(),
+            event_address.clone(),
+            ton_block::CurrencyCollection::default(),
+        );
+        let mut message = Message::with_int_header(header);
+        message.set_body(body.into());
+        message
+    };
+    transaction.write_in_msg(Some(&message)).unwrap();
+    transaction
+}
+
+#[wasm_bindgen_test]
+fn vote_timeline_counts_each_relay_once() {
+    use ton_explorer_event_details::contract::EventStatus;
+    use ton_explorer_event_details::history::{build_vote_timeline, VoteFunctions, VoteKind};
+
+    let abi = Contract::load(Cursor::new(include_str!("TonEvent.abi.json"))).unwrap();
+    let address = |n: u8| MsgAddressInt::from_str(&format!("0:{}", hex::encode([n; 32]))).unwrap();
+    let event_address = address(0xee);
+
+    let transactions = vec![
+        vote_transaction(&event_address, 1, "constructor", &address(1)),
+        vote_transaction(&event_address, 2, "confirm", &address(1)),
+        vote_transaction(&event_address, 3, "reject", &address(2)),
+        vote_transaction(&event_address, 4, "confirm", &address(3)),
+        vote_transaction(&event_address, 5, "confirm", &address(4)),
+    ];
+
+    let votes = build_vote_timeline(&transactions, &event_address, &abi, &VoteFunctions::default(), 2, 2).unwrap();
+    let votes = votes
+        .iter()
+        .map(|vote| {
+            (
+                vote.relay.clone(),
+                vote.kind,
+                vote.lt,
+                vote.status,
+                vote.confirmations,
+                vote.rejections,
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        votes,
+        vec![
+            (address(1), VoteKind::Deploy, 1, EventStatus::InProcess, 1, 0),
+            (address(2), VoteKind::Reject, 3, EventStatus::InProcess, 1, 1),
+            (address(3), VoteKind::Confirm, 4, EventStatus::Confirmed, 2, 1),
+            (address(4), VoteKind::Confirm, 5, EventStatus::Confirmed, 3, 1),
+        ]
+    );
+
+    let foreign = vec![vote_transaction(&address(0xdd), 1, "confirm", &address(1))];
+    assert_eq!(
+        build_vote_timeline(&foreign, &event_address, &abi, &VoteFunctions::default(), 2, 2).err(),
+        Some("Transaction belongs to another account")
+    );
+
+    // vote functions are looked up in the ABI instead of being matched by name only
+    let functions = VoteFunctions {
+        confirm: "vote".to_owned(),
+        ..Default::default()
+    };
+    assert_eq!(
+        build_vote_timeline(&transactions, &event_address, &abi, &functions, 2, 2).err(),
+        Some("Contract abi doesn't have confirm function")
+    );
+}
+
+fn big_int(value: &str) -> JsValue {
+    js_sys::BigInt::new(&JsValue::from(value)).unwrap().into()
+}
+
+fn js_get(object: &JsValue, key: &str) -> JsValue {
+    js_sys::Reflect::get(object, &JsValue::from(key)).unwrap()
+}
+
+#[wasm_bindgen_test]
+fn decoded_transaction_is_the_same_as_json_and_object() {
+    use ton_explorer_event_details::decode;
+
+    let abi_json = include_str!("TonEvent.abi.json");
+    let abi = Contract::load(Cursor::new(abi_json)).unwrap();
+    let address = |n: u8| MsgAddressInt::from_str(&format!("0:{}", hex::encode([n; 32]))).unwrap();
+    let transaction = vote_transaction(&address(0xee), 7, "confirm", &address(1));
+
+    let decoded = decode::decode_transaction(&transaction, std::slice::from_ref(&abi)).unwrap();
+    assert_eq!(decoded.lt, 7);
+    let in_msg = decoded.in_msg.as_ref().unwrap();
+    assert_eq!(in_msg.src, Some(address(1)));
+    assert_eq!(in_msg.dst, Some(address(0xee)));
+    let body = in_msg.body.as_ref().unwrap();
+    assert_eq!((body.kind, body.name.as_str()), ("call", "confirm"));
+    assert!(decoded.out_msgs.is_empty() && decoded.events.is_empty());
+
+    // bodies of unknown functions are left undecoded
+    let decoded = decode::decode_transaction(&transaction, &[]).unwrap();
+    assert!(decoded.in_msg.unwrap().body.is_none());
+
+    let boc = ton_types::serialize_toc(&transaction.serialize().unwrap()).unwrap();
+    let input: ton_explorer_event_details::BocInput = js_sys::Uint8Array::from(&boc[..]).unchecked_into();
+    let abis = || js_sys::Array::of1(&JsValue::from(abi_json)).unchecked_into();
+
+    let json = ton_explorer_event_details::decode_transaction(&input, abis(), None).unwrap();
+    let json = serde_json::from_str::<serde_json::Value>(&json).unwrap();
+    assert_eq!(json["lt"], "7");
+    assert_eq!(json["in_msg"]["src"], address(1).to_string());
+    assert_eq!(json["in_msg"]["body"]["name"], "confirm");
+    assert_eq!(json["in_msg"]["body"]["tokens"]["relay"], address(1).to_string());
+    assert_eq!(json["out_msgs"], serde_json::json!([]));
+
+    let object: JsValue = ton_explorer_event_details::decode_transaction_object(&input, abis(), None)
+        .unwrap()
+        .into();
+    assert_eq!(js_get(&object, "lt"), big_int("7"));
+    assert_eq!(js_get(&object, "hash").as_string().unwrap(), json["hash"]);
+    let in_msg = js_get(&object, "in_msg");
+    assert_eq!(js_get(&in_msg, "src").as_string().unwrap(), address(1).to_string());
+    assert_eq!(js_get(&js_get(&in_msg, "body"), "name").as_string().unwrap(), "confirm");
+    assert_eq!(js_sys::Array::from(&js_get(&object, "events")).length(), 0);
+}
+
+#[wasm_bindgen_test]
+fn decoded_message_object_uses_big_ints() {
+    let abi = r#"{
+        "ABI version": 2,
+        "header": [],
+        "functions": [
+            {"name": "transfer", "inputs": [{"name": "amount", "type": "uint128"}, {"name": "values", "type": "int32[]"}], "outputs": []}
+        ],
+        "data": [],
+        "events": []
+    }"#;
+    let contract = Contract::load(Cursor::new(abi)).unwrap();
+    let function = contract.function("transfer").unwrap();
+    let tokens = vec![
+        named_token("amount", uint_token(12_345_678_901_234_567_890, 128)),
+        named_token(
+            "values",
+            TokenValue::Array(vec![TokenValue::Int(ton_abi::Int::new(-1, 32))]),
+        ),
+    ];
+    let body = function.encode_input(&HashMap::new(), &tokens, true, None).unwrap();
+
+    let src = MsgAddressInt::from_str(&format!("0:{}", "11".repeat(32))).unwrap();
+    let dst = MsgAddressInt::from_str(&format!("-1:{}", "22".repeat(32))).unwrap();
+    let header =
+        ton_block::InternalMessageHeader::with_addresses(src.clone(), dst, ton_block::CurrencyCollection::default());
+    let mut message = Message::with_int_header(header);
+    message.set_body(body.into());
+    let boc = ton_types::serialize_toc(&message.serialize().unwrap()).unwrap();
+    let input: ton_explorer_event_details::BocInput = js_sys::Uint8Array::from(&boc[..]).unchecked_into();
+    let abis = || js_sys::Array::of1(&JsValue::from(abi)).unchecked_into();
+
+    let json = ton_explorer_event_details::decode_message(&input, abis(), None).unwrap();
+    let json = serde_json::from_str::<serde_json::Value>(&json).unwrap();
+    assert_eq!(json["type"], "internal");
+    assert_eq!(json["src"], src.to_string());
+    assert_eq!(json["body"]["kind"], "call");
+    assert_eq!(json["body"]["tokens"]["amount"], "12345678901234567890");
+    assert_eq!(json["body"]["tokens"]["values"], serde_json::json!(["-1"]));
+
+    let object: JsValue = ton_explorer_event_details::decode_message_object(&input, abis(), None)
+        .unwrap()
+        .into();
+    assert_eq!(js_get(&object, "type").as_string().unwrap(), "internal");
+    let tokens = js_get(&js_get(&object, "body"), "tokens");
+    assert_eq!(js_get(&tokens, "amount"), big_int("12345678901234567890"));
+    assert_eq!(js_sys::Array::from(&js_get(&tokens, "values")).get(0), big_int("-1"));
+}
+
+#[wasm_bindgen_test]
+fn token_burn_amount_is_available_as_big_int() {
+    let account_state: ton_explorer_event_details::BocInput =
+        js_sys::Uint8Array::from(&include_bytes!("ton_event_account.boc")[..]).unchecked_into();
+    let event = ton_explorer_event_details::get_details(&account_state, None).unwrap();
+
+    let summary = ton_explorer_event_details::get_event_summary(&event, TOKEN_BURN_ABI, Some(9), None)
+        .unwrap()
+        .unwrap();
+    let token_burn = summary.token_burn().unwrap();
+    assert_eq!(
+        JsValue::from(token_burn.amount_big_int().unwrap()),
+        big_int(&token_burn.amount())
+    );
+}
+
+#[wasm_bindgen_test]
+fn vote_timeline_accepts_boc_inputs() {
+    let account_state: ton_explorer_event_details::BocInput =
+        js_sys::Uint8Array::from(&include_bytes!("ton_event_account.boc")[..]).unchecked_into();
+    let event = ton_explorer_event_details::get_details(&account_state, None).unwrap();
+
+    let address = |n: u8| MsgAddressInt::from_str(&format!("0:{}", hex::encode([n; 32]))).unwrap();
+    let event_address = address(0xee);
+    let boc = |lt, function, relay| {
+        let transaction = vote_transaction(&event_address, lt, function, &address(relay));
+        ton_types::serialize_toc(&transaction.serialize().unwrap()).unwrap()
+    };
+
+    let transactions = js_sys::Array::of3(
+        &js_sys::Uint8Array::from(&boc(1, "constructor", 1)[..]),
+        &JsValue::from(base64::encode(&boc(2, "confirm", 2))),
+        &JsValue::from(format!("0x{}", hex::encode(&boc(3, "reject", 3)))),
+    );
+    let votes = ton_explorer_event_details::get_vote_timeline(
+        &event,
+        &event_address.to_string(),
+        transactions.unchecked_into(),
+        include_str!("TonEvent.abi.json"),
+        None,
+        None,
+    )
+    .unwrap();
+    let relays = js_sys::Array::from(&votes)
+        .iter()
+        .map(|vote| {
+            js_sys::Reflect::get(&vote, &JsValue::from("relay"))
+                .unwrap()
+                .as_string()
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        relays,
+        vec![address(1).to_string(), address(2).to_string(), address(3).to_string()]
+    );
+
+    let transactions = js_sys::Array::of1(&JsValue::from(1));
+    assert!(ton_explorer_event_details::get_vote_timeline(
+        &event,
+        &event_address.to_string(),
+        transactions.unchecked_into(),
+        include_str!("TonEvent.abi.json"),
+        None,
+        None,
+    )
+    .is_err());
+}
+
+fn uint_token(number: u64, size: usize) -> TokenValue {
+    TokenValue::Uint(ton_abi::Uint {
+        number: BigUint::from(number),
+        size,
+    })
+}
+
+fn address_token(address: &MsgAddressInt) -> TokenValue {
+    match address.clone() {
+        MsgAddressInt::AddrStd(address) => TokenValue::Address(ton_block::MsgAddress::AddrStd(address)),
+        MsgAddressInt::AddrVar(address) => TokenValue::Address(ton_block::MsgAddress::AddrVar(address)),
+    }
+}
+
+fn named_token(name: &str, value: TokenValue) -> ton_abi::Token {
+    ton_abi::Token {
+        name: name.to_owned(),
+        value,
+    }
+}
+
+#[wasm_bindgen_test]
+fn bridge_details_are_parsed_from_getter_outputs() {
+    use ton_explorer_event_details::bridge;
+
+    let relay = |n: u8| MsgAddressInt::from_str(&format!("0:{}", hex::encode([n; 32]))).unwrap();
+    let configuration = vec![named_token(
+        "value0",
+        TokenValue::Tuple(vec![
+            named_token("eventConfigurationRequiredConfirmations", uint_token(2, 16)),
+            named_token("eventConfigurationRequiredRejects", uint_token(3, 16)),
+            named_token("bridgeConfigurationUpdateRequiredConfirmations", uint_token(4, 16)),
+            named_token("bridgeConfigurationUpdateRequiredRejects", uint_token(5, 16)),
+            named_token("bridgeRelayUpdateRequiredConfirmations", uint_token(6, 16)),
+            named_token("bridgeRelayUpdateRequiredRejects", uint_token(7, 16)),
+            named_token("active", TokenValue::Bool(true)),
+        ]),
+    )];
+    let eth_address = ethabi::Address::from_str(RELAY_ETH_ADDRESS).unwrap();
+    let accounts = |eth_accounts: Vec<TokenValue>| {
+        vec![
+            named_token("tonAccounts", TokenValue::Array(vec![address_token(&relay(1))])),
+            named_token("ethereumAccounts", TokenValue::Array(eth_accounts)),
+        ]
+    };
+    let eth_account = TokenValue::Uint(ton_abi::Uint {
+        number: BigUint::from_bytes_be(eth_address.as_bytes()),
+        size: 160,
+    });
+
+    let details = bridge::parse_details(configuration.clone(), accounts(vec![eth_account.clone()])).unwrap();
+    let configuration_values = &details.configuration;
+    assert_eq!(configuration_values.event_configuration_required_confirmations, 2);
+    assert_eq!(configuration_values.event_configuration_required_rejections, 3);
+    assert_eq!(configuration_values.bridge_relay_update_required_rejections, 7);
+    assert!(configuration_values.active);
+    assert_eq!(details.relays.len(), 1);
+    assert_eq!(details.relays[0].ton_address, relay(1));
+    assert_eq!(details.relays[0].eth_address, eth_address);
+
+    // every TON account must have an Ethereum account
+    assert!(bridge::parse_details(configuration.clone(), accounts(vec![eth_account.clone(), eth_account])).is_err());
+    assert!(bridge::parse_details(Vec::new(), accounts(Vec::new())).is_err());
+
+    // the event contract has neither of the Bridge getters
+    let (code, data) = event_code_and_data();
+    let event_abi = Contract::load(Cursor::new(include_str!("TonEvent.abi.json"))).unwrap();
+    assert_eq!(
+        bridge::get_details(code, data, &event_abi).err(),
+        Some("Bridge abi doesn't have getBridgeConfiguration function")
+    );
+}
+
+#[wasm_bindgen_test]
+fn configuration_details_are_parsed_from_getter_outputs() {
+    use ton_explorer_event_details::configuration;
+
+    let (code, data) = event_code_and_data();
+    let address = |n: u8| MsgAddressInt::from_str(&format!("0:{}", hex::encode([n; 32]))).unwrap();
+    let eth_address = ethabi::Address::from_str(RELAY_ETH_ADDRESS).unwrap();
+    let eth_address_token = TokenValue::Uint(ton_abi::Uint {
+        number: BigUint::from_bytes_be(eth_address.as_bytes()),
+        size: 160,
+    });
+
+    let basic_init_data = named_token(
+        "value0",
+        TokenValue::Tuple(vec![
+            named_token("eventABI", TokenValue::Bytes(TOKEN_BURN_ABI.as_bytes().to_vec())),
+            named_token("eventRequiredConfirmations", uint_token(2, 16)),
+            named_token("eventRequiredRejects", uint_token(3, 16)),
+            named_token("eventCode", TokenValue::Cell(code.clone())),
+            named_token("bridgeAddress", address_token(&address(1))),
+            named_token("eventInitialBalance", uint_token(1_000_000_000, 128)),
+            named_token("meta", TokenValue::Cell(Cell::default())),
+        ]),
+    );
+    let ton_init_data = named_token(
+        "value1",
+        TokenValue::Tuple(vec![
+            named_token("eventAddress", address_token(&address(2))),
+            named_token("proxyAddress", eth_address_token.clone()),
+            named_token("startTimestamp", uint_token(1617451057, 32)),
+        ]),
+    );
+    let ethereum_init_data = named_token(
+        "value1",
+        TokenValue::Tuple(vec![
+            named_token("eventAddress", eth_address_token),
+            named_token("eventBlocksToConfirm", uint_token(12, 16)),
+            named_token("proxyAddress", address_token(&address(3))),
+            named_token("startBlockNumber", uint_token(11_000_000, 32)),
+        ]),
+    );
+
+    let details =
+        configuration::parse_ton_event_configuration_details(vec![basic_init_data.clone(), ton_init_data.clone()])
+            .unwrap();
+    assert_eq!(details.basic_init_data.event_abi, TOKEN_BURN_ABI);
+    assert_eq!(details.basic_init_data.event_required_confirmations, 2);
+    assert_eq!(details.basic_init_data.event_required_rejections, 3);
+    assert_eq!(details.basic_init_data.event_code, code);
+    assert_eq!(details.basic_init_data.bridge_address, address(1));
+    assert_eq!(
+        details.basic_init_data.event_initial_balance,
+        BigUint::from(1_000_000_000u64)
+    );
+    assert_eq!(details.init_data.event_address, address(2));
+    assert_eq!(details.init_data.proxy_address, eth_address);
+    assert_eq!(details.init_data.start_timestamp, 1617451057);
+
+    let details = configuration::parse_ethereum_event_configuration_details(vec![
+        basic_init_data.clone(),
+        ethereum_init_data.clone(),
+    ])
+    .unwrap();
+    assert_eq!(details.init_data.event_address, eth_address);
+    assert_eq!(details.init_data.event_blocks_to_confirm, 12);
+    assert_eq!(details.init_data.proxy_address, address(3));
+    assert_eq!(details.init_data.start_block_number, 11_000_000);
+
+    // init data of one configuration kind is not accepted for the other one
+    assert!(
+        configuration::parse_ton_event_configuration_details(vec![basic_init_data.clone(), ethereum_init_data])
+            .is_err()
+    );
+    assert!(configuration::parse_ethereum_event_configuration_details(vec![basic_init_data, ton_init_data]).is_err());
+
+    let proxy_abi = Contract::load(Cursor::new(include_str!("TokenEventProxy.abi.json"))).unwrap();
+    assert_eq!(
+        configuration::get_ton_event_configuration_details(code, data, &proxy_abi).err(),
+        Some("Configuration abi doesn't have getDetails function")
+    );
+}
+
+#[wasm_bindgen_test]
+fn inspect_counts_the_whole_tree() {
+    use ton_explorer_event_details::inspect;
+
+    let leaf = code_cell("01", Vec::new());
+    let inner = code_cell("02", vec![leaf.clone(), leaf.clone()]);
+    let root = code_cell("03", vec![inner.clone(), leaf.clone()]);
+    let leaf_hash = leaf.repr_hash().to_hex_string();
+
+    for max_depth in [None, Some(0), Some(1)].iter() {
+        let result = inspect::inspect_cell(&root, *max_depth).unwrap();
+        assert_eq!(result["total_cells"], 5);
+        assert_eq!(result["unique_cells"], 3);
+        assert_eq!(result["duplicates"], serde_json::json!({ leaf_hash.clone(): 3 }));
+    }
+
+    let result = inspect::inspect_cell(&root, None).unwrap();
+    let inner_refs = &result["root"]["refs"][0]["refs"];
+    assert_eq!(inner_refs[0]["duplicate"], false);
+    assert_eq!(inner_refs[1]["duplicate"], true);
+    assert_eq!(result["root"]["refs"][1]["duplicate"], true);
+
+    let result = inspect::inspect_cell(&root, Some(0)).unwrap();
+    assert_eq!(result["root"]["refs"][0]["truncated"], true);
+}
+
+#[wasm_bindgen_test]
+fn format_amount_trims_fraction() {
+    use ton_explorer_event_details::summary::format_amount;
+
+    let format = |amount: u64, decimals: u8| format_amount(&BigUint::from(amount), decimals);
+    assert_eq!(format(1_500_000_000, 0), "1500000000");
+    assert_eq!(format(0, 0), "0");
+    assert_eq!(format(0, 9), "0");
+    assert_eq!(format(1_500_000_000, 9), "1.5");
+    assert_eq!(format(1_000, 3), "1");
+    assert_eq!(format(12_000, 2), "120");
+    assert_eq!(format(12_345, 2), "123.45");
+    assert_eq!(format(5, 3), "0.005");
+}
+
+fn event_data(event_abi: &str, tokens: Vec<ton_abi::Token>) -> Cell {
+    let mut id = BuilderData::new();
+    id.append_u32(ton_explorer_event_details::eth::parse_event_abi(event_abi).unwrap().id)
+        .unwrap();
+    TokenValue::pack_values_into_chain(&tokens, vec![id], 2)
+        .unwrap()
+        .into_cell()
+        .unwrap()
+}
+
+#[wasm_bindgen_test]
+fn token_burn_summary_is_parsed() {
+    use ton_explorer_event_details::summary::{self, EventSummary};
+
+    let eth_address = ethabi::Address::from_str(RELAY_ETH_ADDRESS).unwrap();
+    let wid = named_token("wid", TokenValue::Int(ton_abi::Int::new(-1, 8)));
+    let addr = named_token(
+        "addr",
+        TokenValue::Uint(ton_abi::Uint {
+            number: BigUint::from_bytes_be(&[0x11; 32]),
+            size: 256,
+        }),
+    );
+    let tokens = named_token("tokens", uint_token(1_500_000_000, 128));
+    let ethereum_address = named_token(
+        "ethereum_address",
+        TokenValue::Uint(ton_abi::Uint {
+            number: BigUint::from_bytes_be(eth_address.as_bytes()),
+            size: 160,
+        }),
+    );
+
+    let event_tokens = vec![wid.clone(), addr.clone(), tokens, ethereum_address.clone()];
+    let event_data = event_data(TOKEN_BURN_ABI, event_tokens.clone());
+    let token_burn = match summary::summarize_event(event_data, TOKEN_BURN_ABI).unwrap() {
+        Some(EventSummary::TokenBurn(token_burn)) => token_burn,
+        None => panic!("TokenBurn event is not recognized"),
+    };
+    assert_eq!(token_burn.sender.to_string(), format!("-1:{}", "11".repeat(32)));
+    assert_eq!(token_burn.amount, BigUint::from(1_500_000_000u64));
+    assert_eq!(token_burn.ethereum_address, eth_address);
+
+    // other events are not summarized
+    let other_abi = TOKEN_BURN_ABI.replace("TokenBurn", "TokenMint");
+    let other_data = event_data(&other_abi, event_tokens);
+    assert!(summary::summarize_event(other_data, &other_abi).unwrap().is_none());
+
+    // `tokens` field is required
+    let incomplete_abi = r#"{"name":"TokenBurn","inputs":[{"name":"wid","type":"int8"},{"name":"addr","type":"uint256"},{"name":"ethereum_address","type":"uint160"}],"outputs":[]}"#;
+    let incomplete_data = event_data(incomplete_abi, vec![wid, addr, ethereum_address]);
+    assert_eq!(
+        summary::summarize_event(incomplete_data, incomplete_abi).err(),
+        Some("Invalid TokenBurn event data")
+    );
 }